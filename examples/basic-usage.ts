@@ -24,12 +24,15 @@ const DAO_CONFIG = {
   minReputationToVote: 100,   // 100 points minimum to vote
   categoryWeights: [2500, 2500, 2500, 2500], // Equal 25% weights
   roleThresholds: [100, 500, 1000, 2500, 5000], // Role unlock thresholds
+  voteHistoryDepth: 10,       // Last 10 votes kept per voter→target pair
 };
 
 export class ReputationManager {
   private program: Program<DaoReputationScoreboard>;
   private provider: anchor.AnchorProvider;
   private configPDA: PublicKey;
+  private leaderboardPDA: PublicKey;
+  private categoryLeaderboardPDAs: Record<string, PublicKey>;
 
   constructor(
     connection: Connection, 
@@ -55,6 +58,58 @@ export class ReputationManager {
       [Buffer.from("reputation_config")],
       this.program.programId
     );
+
+    // Derive leaderboard PDAs
+    [this.leaderboardPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("leaderboard")],
+      this.program.programId
+    );
+    this.categoryLeaderboardPDAs = {};
+    ["governance", "development", "community", "treasury"].forEach((category, index) => {
+      [this.categoryLeaderboardPDAs[category]] = PublicKey.findProgramAddressSync(
+        [Buffer.from("category_leaderboard"), Buffer.from([index])],
+        this.program.programId
+      );
+    });
+  }
+
+  /**
+   * Rank index account registered in the system config
+   */
+  private async rankIndex(): Promise<PublicKey> {
+    const config = await this.program.account.reputationConfig.fetch(this.configPDA);
+    return config.rankIndex;
+  }
+
+  /**
+   * Current season's data account, or null if that season was never started on-chain
+   */
+  private async currentSeasonData(): Promise<PublicKey | null> {
+    const config = await this.program.account.reputationConfig.fetch(this.configPDA);
+    const seasonId = Buffer.alloc(4);
+    seasonId.writeUInt32LE(config.currentSeason);
+
+    const [seasonDataPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("season_data"), seasonId],
+      this.program.programId
+    );
+    const accountInfo = await this.provider.connection.getAccountInfo(seasonDataPDA);
+    return accountInfo ? seasonDataPDA : null;
+  }
+
+  /**
+   * Ranking accounts updated alongside reputation changes
+   */
+  private async leaderboardAccounts() {
+    return {
+      config: this.configPDA,
+      rankIndex: await this.rankIndex(),
+      leaderboard: this.leaderboardPDA,
+      governanceLeaderboard: this.categoryLeaderboardPDAs.governance,
+      developmentLeaderboard: this.categoryLeaderboardPDAs.development,
+      communityLeaderboard: this.categoryLeaderboardPDAs.community,
+      treasuryLeaderboard: this.categoryLeaderboardPDAs.treasury,
+    };
   }
 
   /**
@@ -71,10 +126,15 @@ export class ReputationManager {
           DAO_CONFIG.dailyVoteLimit,
           new anchor.BN(DAO_CONFIG.minReputationToVote),
           DAO_CONFIG.categoryWeights,
-          DAO_CONFIG.roleThresholds.map(t => new anchor.BN(t))
+          DAO_CONFIG.roleThresholds.map(t => new anchor.BN(t)),
+          DAO_CONFIG.voteHistoryDepth
         )
         .accounts({
           config: this.configPDA,
+          collusionRegistry: PublicKey.findProgramAddressSync(
+            [Buffer.from("collusion_registry")],
+            this.program.programId
+          )[0],
           admin: this.provider.wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -107,6 +167,8 @@ export class ReputationManager {
         .accounts({
           config: this.configPDA,
           userReputation: userReputationPDA,
+          rankIndex: await this.rankIndex(),
+          userTokenAccount: null, // governance token account when registration is token-gated
           user: userKeypair.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -157,6 +219,26 @@ export class ReputationManager {
       this.program.programId
     );
 
+    const [voterSummaryPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("counterpart_summary"), voterKeypair.publicKey.toBuffer()],
+      this.program.programId
+    );
+
+    const [targetSummaryPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("counterpart_summary"), targetPublicKey.toBuffer()],
+      this.program.programId
+    );
+
+    const [collusionRegistryPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collusion_registry")],
+      this.program.programId
+    );
+
+    const [stakeVaultPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_vault")],
+      this.program.programId
+    );
+
     // Convert category string to enum object
     const categoryEnum = { [category]: {} };
 
@@ -168,7 +250,22 @@ export class ReputationManager {
           voterReputation: voterReputationPDA,
           targetReputation: targetReputationPDA,
           votingRecord: votingRecordPDA,
+          voterSummary: voterSummaryPDA,
+          targetSummary: targetSummaryPDA,
+          collusionRegistry: collusionRegistryPDA,
+          voterTokenAccount: null, // governance token account when voting is token-gated
+          downvoteStake: {
+            stakeVault: isUpvote ? null : stakeVaultPDA, // lamport stakes are escrowed in the vault
+            voterStakeAccount: null,
+            tokenVault: null,
+            stakeMint: null,
+            tokenProgram: null,
+          },
+          leaderboards: await this.leaderboardAccounts(),
+          seasonData: await this.currentSeasonData(),
+          delegation: null, // set when a delegate signs for the voter
           voter: voterKeypair.publicKey,
+          authority: voterKeypair.publicKey,
           target: targetPublicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -306,7 +403,12 @@ export class ReputationManager {
   /**
    * Start a new competitive season (admin only)
    */
-  async startNewSeason(seasonName: string, durationDays: number, seasonId: number): Promise<void> {
+  async startNewSeason(
+    seasonName: string,
+    durationDays: number,
+    seasonId: number,
+    leaderboardSize: number = 25
+  ): Promise<void> {
     console.log(`🏁 Starting new season: ${seasonName} (${durationDays} days)`);
 
     const [seasonDataPDA] = PublicKey.findProgramAddressSync(
@@ -316,7 +418,7 @@ export class ReputationManager {
 
     try {
       const tx = await this.program.methods
-        .startNewSeason(seasonName, durationDays, seasonId)
+        .startNewSeason(seasonName, durationDays, seasonId, leaderboardSize)
         .accounts({
           config: this.configPDA,
           seasonData: seasonDataPDA,
@@ -350,6 +452,7 @@ export class ReputationManager {
         .getLeaderboard(categoryEnum, page, pageSize)
         .accounts({
           config: this.configPDA,
+          leaderboard: category ? this.categoryLeaderboardPDAs[category] : this.leaderboardPDA,
         })
         .view();
 
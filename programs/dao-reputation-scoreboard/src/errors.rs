@@ -110,4 +110,139 @@ pub enum ReputationError {
     
     #[msg("Role thresholds are not in ascending order")]
     InvalidRoleThresholds,
-}
\ No newline at end of file
+
+    #[msg("Rank index has already been created")]
+    RankIndexInitializationNotAllowed,
+
+    #[msg("Ranking crank accounts must be passed in ascending user order after the cursor")]
+    CrankOutOfOrder,
+
+    #[msg("Remaining accounts do not match the bulk operation entries")]
+    BulkAccountsMismatch,
+
+    #[msg("Season must end before its seasonal data can be reset")]
+    SeasonStillActive,
+
+    #[msg("Import record is not part of the migration's Merkle root")]
+    InvalidMerkleProof,
+
+    #[msg("Every record of this migration has already been claimed")]
+    MigrationFullyClaimed,
+
+    #[msg("Batch job is completed or cancelled")]
+    BatchJobNotActive,
+
+    #[msg("Batch job does not match this bulk operation")]
+    BatchJobMismatch,
+
+    #[msg("Batch job would process more entries than it was created for")]
+    BatchJobOverrun,
+
+    #[msg("Vote can no longer be retracted or amended")]
+    VoteAmendWindowExpired,
+
+    #[msg("Daily vote limit exceeded for this target")]
+    PairVoteLimitExceeded,
+
+    #[msg("Votes must be committed and revealed while commit-reveal mode is enabled")]
+    CommitRevealRequired,
+
+    #[msg("Commit-reveal mode is not enabled")]
+    CommitRevealDisabled,
+
+    #[msg("A committed vote on this target is still waiting to be revealed")]
+    CommitmentPending,
+
+    #[msg("No committed vote is waiting to be revealed")]
+    NoPendingCommitment,
+
+    #[msg("Committed vote cannot be revealed yet")]
+    RevealWindowNotOpen,
+
+    #[msg("Reveal window for this committed vote has closed")]
+    RevealWindowClosed,
+
+    #[msg("Revealed vote does not match the commitment")]
+    CommitmentMismatch,
+
+    #[msg("Committed vote can still be revealed")]
+    RevealWindowStillOpen,
+
+    #[msg("A governance token account is required while token gating is enabled")]
+    TokenAccountRequired,
+
+    #[msg("Token account is not the signer's account for the governance token mint")]
+    InvalidTokenAccount,
+
+    #[msg("Governance token balance is below the required minimum")]
+    InsufficientTokenBalance,
+
+    #[msg("Downvote stake accounts are required while downvotes are staked")]
+    StakeAccountsMissing,
+
+    #[msg("Stake account does not match the stake vault or mint")]
+    InvalidStakeAccount,
+
+    #[msg("Stake recipient is not the expected wallet or token account")]
+    InvalidStakeRecipient,
+
+    #[msg("The previous downvote on this target still has its stake in escrow")]
+    DownvoteStakeOutstanding,
+
+    #[msg("No downvote stake is held for this pair")]
+    NoDownvoteStake,
+
+    #[msg("Challenge window for this downvote has closed")]
+    ChallengeWindowClosed,
+
+    #[msg("Downvote stake is returned once its challenge window has closed")]
+    ChallengeWindowStillOpen,
+
+    #[msg("Downvote was already appealed")]
+    DownvoteAlreadyAppealed,
+
+    #[msg("Appealed downvote stakes must be resolved by a moderator")]
+    DownvoteUnderAppeal,
+
+    #[msg("Only the voter or an active delegate can cast this vote")]
+    DelegationRequired,
+
+    #[msg("Delegation does not cover this category or has expired")]
+    DelegationNotActive,
+
+    #[msg("Cannot delegate votes to yourself")]
+    CannotDelegateToSelf,
+
+    #[msg("Delegation needs at least one category and an expiry in the future")]
+    InvalidDelegation,
+
+    #[msg("Voter does not hold enough points in this category to vote in it")]
+    InsufficientCategoryPoints,
+
+    #[msg("Voter's role level is too low to vote in this category")]
+    InsufficientRoleLevel,
+
+    #[msg("Voter lacks the achievement required to vote in this category")]
+    MissingRequiredAchievement,
+
+    #[msg("Season was already closed for this user")]
+    SeasonAlreadyClosed,
+
+    #[msg("Bulk call must start at the batch job's cursor")]
+    BatchJobOutOfOrder,
+
+    #[msg("Batch job entries differ from those committed at creation")]
+    BatchJobEntriesMismatch,
+
+    #[msg("Ranking crank accounts must be writable user reputation PDAs")]
+    InvalidCrankAccount,
+
+    #[msg("Backfill accounts must be user reputation PDAs in ascending user order after the cursor")]
+    InvalidBackfillAccount,
+
+    #[msg("Rank index is still being backfilled with existing users")]
+    RankIndexBackfillPending,
+
+    #[msg("Only the user or the admin can claim an import over an existing reputation")]
+    UnauthorizedImportClaim,
+}
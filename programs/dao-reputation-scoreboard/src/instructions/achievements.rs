@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
 use crate::utils::*;
+use crate::instructions::leaderboard::*;
 
 /// Award achievement badges to users
 pub fn award_achievement(
@@ -26,30 +27,16 @@ pub fn award_achievement(
     );
 
     // Award the achievement
-    user_reputation.award_achievement(achievement_type);
-    user_reputation.last_updated = ReputationUtils::get_current_timestamp();
+    let current_time = ReputationUtils::get_current_timestamp();
+    let previous_score = user_reputation.total_score;
+    user_reputation.award_achievement(achievement_type, current_time, config.current_season);
+    user_reputation.last_updated = current_time;
 
-    // Optionally award bonus points for achievements
-    let bonus_points = match achievement_type {
-        AchievementType::FirstVote => 50,
-        AchievementType::WeeklyStreak => 100,
-        AchievementType::MonthlyStreak => 500,
-        AchievementType::TopContributor => 1000,
-        AchievementType::ConsistentVoter => 200,
-        AchievementType::CategoryExpert => 300,
-        AchievementType::SeasonWinner => 2000,
-        AchievementType::CommunityBuilder => 750,
-    };
-
-    // Add bonus points to governance category (achievements are governance-related)
-    user_reputation.category_points[0] += bonus_points;
-    user_reputation.calculate_total_score(&config.category_weights);
-
-    // Update role level if changed
-    user_reputation.role_level = ReputationUtils::calculate_role_level(
-        user_reputation.total_score,
-        &config.role_thresholds,
-    );
+    // Award bonus points for achievements
+    let bonus_points = user_reputation.apply_achievement_bonus(achievement_type, config);
+
+    // Keep the on-chain leaderboards in sync with the new scores
+    ctx.accounts.leaderboards.record(user_reputation, previous_score, current_time)?;
 
     msg!(
         "Achievement {:?} awarded to user {} by admin {} (bonus: {} points)",
@@ -100,7 +87,9 @@ pub fn check_user_achievements(
 pub fn auto_award_achievements(
     ctx: Context<AutoAwardAchievements>,
 ) -> Result<Vec<AchievementType>> {
+    let config = &ctx.accounts.config;
     let user_reputation = &mut ctx.accounts.user_reputation;
+    let current_time = ReputationUtils::get_current_timestamp();
     let mut newly_awarded = Vec::new();
 
     // Check for automatic achievements
@@ -117,13 +106,13 @@ pub fn auto_award_achievements(
     for achievement in achievements_to_check.iter() {
         if !user_reputation.has_achievement(*achievement) &&
            ReputationUtils::should_award_achievement(user_reputation, *achievement) {
-            user_reputation.award_achievement(*achievement);
+            user_reputation.award_achievement(*achievement, current_time, config.current_season);
             newly_awarded.push(*achievement);
         }
     }
 
     if !newly_awarded.is_empty() {
-        user_reputation.last_updated = ReputationUtils::get_current_timestamp();
+        user_reputation.last_updated = current_time;
         
         msg!(
             "Auto-awarded {} achievements to user {}",
@@ -232,8 +221,7 @@ pub fn revoke_achievement(
     );
 
     // Remove the achievement
-    let bit_position = achievement_type as u8;
-    user_reputation.achievements &= !(1u32 << bit_position);
+    user_reputation.revoke_achievement(achievement_type);
     user_reputation.last_updated = ReputationUtils::get_current_timestamp();
 
     msg!(
@@ -246,7 +234,6 @@ pub fn revoke_achievement(
     Ok(())
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 // pub struct AchievementProgress {
 //     pub achievement_type: AchievementType,
 //     pub earned: bool,
@@ -271,6 +258,8 @@ pub struct AwardAchievement<'info> {
     )]
     pub user_reputation: Account<'info, UserReputation>,
 
+    pub leaderboards: LeaderboardAccounts<'info>,
+
     #[account(
         constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
     )]
@@ -291,6 +280,12 @@ pub struct CheckUserAchievements<'info> {
 
 #[derive(Accounts)]
 pub struct AutoAwardAchievements<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
     #[account(
         mut,
         seeds = [b"user_reputation", user.key().as_ref()],
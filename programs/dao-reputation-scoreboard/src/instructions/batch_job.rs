@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::*;
+
+/// Create a batch job that bulk operations of `kind` advance (admin only)
+pub fn create_batch_job(
+    ctx: Context<CreateBatchJob>,
+    job_id: u64,
+    kind: BatchJobKind,
+    params: BatchJobParams,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let current_time = ReputationUtils::get_current_timestamp();
+
+    // Validate admin authority
+    require!(
+        ctx.accounts.admin.key() == config.admin,
+        ReputationError::UnauthorizedAdmin
+    );
+
+    require!(
+        params.total_entries > 0,
+        ReputationError::InvalidConfigurationValues
+    );
+
+    ctx.accounts.batch_job.set_inner(BatchJob {
+        job_id,
+        kind,
+        params,
+        cursor: 0,
+        entries_chain: [0; 32],
+        processed_count: 0,
+        failed_count: 0,
+        created_by: ctx.accounts.admin.key(),
+        created_at: current_time,
+        updated_at: current_time,
+        status: BatchJobStatus::Active,
+        bump: ctx.bumps.batch_job,
+    });
+
+    msg!(
+        "Batch job {} created by admin: {} ({:?}, {} entries)",
+        job_id,
+        ctx.accounts.admin.key(),
+        kind,
+        params.total_entries
+    );
+
+    Ok(())
+}
+
+/// Stop an active batch job; its progress stays on chain for auditing (admin only)
+pub fn cancel_batch_job(ctx: Context<CancelBatchJob>, job_id: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let batch_job = &mut ctx.accounts.batch_job;
+
+    // Validate admin authority
+    require!(
+        ctx.accounts.admin.key() == config.admin,
+        ReputationError::UnauthorizedAdmin
+    );
+
+    require!(
+        batch_job.status == BatchJobStatus::Active,
+        ReputationError::BatchJobNotActive
+    );
+
+    batch_job.status = BatchJobStatus::Cancelled;
+    batch_job.updated_at = ReputationUtils::get_current_timestamp();
+
+    msg!(
+        "Batch job {} cancelled at entry {} of {}",
+        job_id,
+        batch_job.cursor,
+        batch_job.params.total_entries
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(job_id: u64)]
+pub struct CreateBatchJob<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = BatchJob::LEN,
+        seeds = [b"batch_job", &job_id.to_le_bytes()[..]],
+        bump
+    )]
+    pub batch_job: Account<'info, BatchJob>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(job_id: u64)]
+pub struct CancelBatchJob<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"batch_job", &job_id.to_le_bytes()[..]],
+        bump = batch_job.bump
+    )]
+    pub batch_job: Account<'info, BatchJob>,
+
+    #[account(
+        constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+}
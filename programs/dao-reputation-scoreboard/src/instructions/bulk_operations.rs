@@ -2,14 +2,18 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program::{System};
 use crate::state::*;
 use crate::errors::*;
-// Removed unused import
+use crate::utils::*;
+use crate::instructions::leaderboard::*;
 
-/// Bulk admin operation to update multiple users
-pub fn bulk_update_reputation(
-    ctx: Context<BulkUpdateReputation>,
+/// Bulk admin operation to update multiple users.
+/// `remaining_accounts[i]` must be the writable reputation PDA of `updates[i].user`.
+pub fn bulk_update_reputation<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BulkUpdateReputation<'info>>,
     updates: Vec<BulkReputationUpdate>,
-) -> Result<()> {
+    start_index: u32,
+) -> Result<BulkOperationResult> {
     let config = &ctx.accounts.config;
+    let current_time = ReputationUtils::get_current_timestamp();
 
     // Validate admin authority
     require!(
@@ -22,31 +26,66 @@ pub fn bulk_update_reputation(
         updates.len() <= 50,
         ReputationError::BulkOperationTooLarge
     );
+    require!(
+        ctx.remaining_accounts.len() == updates.len(),
+        ReputationError::BulkAccountsMismatch
+    );
+
+    let mut result = BulkOperationResult::default();
+
+    for (update, account_info) in updates.iter().zip(ctx.remaining_accounts.iter()) {
+        // Entries with an unknown role level or a mismatched account fail individually
+        let user_reputation = if update.role_level <= 5 {
+            ReputationUtils::load_user_reputation(account_info, &update.user)
+        } else {
+            None
+        };
+        let Some(mut user_reputation) = user_reputation else {
+            msg!("Bulk update skipped for user {}", update.user);
+            result.record(false);
+            continue;
+        };
+
+        let previous_score = user_reputation.total_score;
+
+        user_reputation.apply_import(
+            update,
+            &config.category_weights,
+            config.current_season,
+            current_time,
+        );
+
+        // Keep the on-chain leaderboards in sync with the new scores
+        ctx.accounts.leaderboards.record(&user_reputation, previous_score, current_time)?;
+        user_reputation.exit(&crate::ID)?;
+
+        result.record(true);
+    }
 
-    // Validate all updates before processing
-    for _update in &updates {
-        // Skip validation for fixed-size byte array
+    if let Some(batch_job) = ctx.accounts.batch_job.as_mut() {
+        batch_job.advance(BatchJobKind::ReputationImport, None, start_index, &updates, &result, current_time)?;
     }
 
     msg!(
-        "Bulk reputation update initiated by admin: {} ({} updates)",
+        "Bulk reputation update by admin: {} ({} applied, {} failed)",
         ctx.accounts.admin.key(),
-        updates.len()
+        result.succeeded,
+        result.failed
     );
 
-    // In a real implementation, you would iterate through each update
-    // and apply the changes to the corresponding user reputation accounts
-    // For this example, we're just logging the operation
-
-    Ok(())
+    Ok(result)
 }
 
-/// Bulk initialize user reputation accounts
-pub fn bulk_initialize_users(
-    ctx: Context<BulkInitializeUsers>,
+/// Bulk initialize user reputation accounts, paid by the admin.
+/// `remaining_accounts[i]` must be the writable reputation PDA of `users[i]`;
+/// entries whose account already exists are skipped.
+pub fn bulk_initialize_users<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BulkInitializeUsers<'info>>,
     users: Vec<Pubkey>,
-) -> Result<()> {
+    start_index: u32,
+) -> Result<BulkOperationResult> {
     let config = &mut ctx.accounts.config;
+    let current_time = ReputationUtils::get_current_timestamp();
 
     // Validate admin authority
     require!(
@@ -59,32 +98,74 @@ pub fn bulk_initialize_users(
         users.len() <= 100,
         ReputationError::BulkOperationTooLarge
     );
-
-    // Check if we would exceed maximum users limit
-    let new_total = config.total_users + users.len() as u64;
     require!(
-        new_total <= 10000, // Maximum users limit
-        ReputationError::MaxUsersLimitReached
+        ctx.remaining_accounts.len() == users.len(),
+        ReputationError::BulkAccountsMismatch
     );
 
-    // Update total users count
-    config.total_users = new_total;
+    let admin = ctx.accounts.admin.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let mut rank_index = ctx.accounts.rank_index.load_mut()?;
+    let mut result = BulkOperationResult::default();
+
+    for (user, account_info) in users.iter().zip(ctx.remaining_accounts.iter()) {
+        let (expected_address, bump) =
+            Pubkey::find_program_address(&[b"user_reputation", user.as_ref()], &crate::ID);
+
+        // Foreign addresses and already-initialized accounts are skipped
+        if account_info.key() != expected_address || !account_info.data_is_empty() {
+            msg!("Bulk initialization skipped for user {}", user);
+            result.record(false);
+            continue;
+        }
+
+        // Check if we would exceed maximum users limit
+        require!(
+            config.total_users < 10000, // Maximum users limit
+            ReputationError::MaxUsersLimitReached
+        );
+
+        ReputationUtils::create_pda_account(
+            &admin,
+            account_info,
+            &system_program,
+            UserReputation::LEN,
+            &[b"user_reputation", user.as_ref(), &[bump]],
+        )?;
+        UserReputation::new(*user, current_time)
+            .try_serialize(&mut &mut account_info.try_borrow_mut_data()?[..])?;
+
+        // Every user enters the rank index with a zero score
+        rank_index.insert(0, current_time)?;
+        config.total_users += 1;
+
+        result.record(true);
+    }
+
+    if let Some(batch_job) = ctx.accounts.batch_job.as_mut() {
+        batch_job.advance(BatchJobKind::UserInitialization, None, start_index, &users, &result, current_time)?;
+    }
 
     msg!(
-        "Bulk user initialization by admin: {} ({} users)",
+        "Bulk user initialization by admin: {} ({} created, {} skipped)",
         ctx.accounts.admin.key(),
-        users.len()
+        result.succeeded,
+        result.failed
     );
 
-    Ok(())
+    Ok(result)
 }
 
-/// Bulk apply reputation decay to inactive users
-pub fn bulk_apply_decay(
-    ctx: Context<BulkApplyDecay>,
+/// Bulk apply reputation decay to inactive users.
+/// `remaining_accounts[i]` must be the writable reputation PDA of `users[i]`;
+/// users with nothing to decay are reported as skipped.
+pub fn bulk_apply_decay<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BulkApplyDecay<'info>>,
     users: Vec<Pubkey>,
-) -> Result<()> {
+    start_index: u32,
+) -> Result<BulkDecaySummary> {
     let config = &ctx.accounts.config;
+    let current_time = ReputationUtils::get_current_timestamp();
 
     // Validate admin authority
     require!(
@@ -103,25 +184,60 @@ pub fn bulk_apply_decay(
         users.len() <= 100,
         ReputationError::BulkOperationTooLarge
     );
+    require!(
+        ctx.remaining_accounts.len() == users.len(),
+        ReputationError::BulkAccountsMismatch
+    );
+
+    let mut summary = BulkDecaySummary::default();
+
+    for (user, account_info) in users.iter().zip(ctx.remaining_accounts.iter()) {
+        // Same eligibility as apply_reputation_decay: some activity, and at least one inactive day not decayed yet
+        let user_reputation = ReputationUtils::load_user_reputation(account_info, user).filter(
+            |user_reputation| user_reputation.last_activity > 0 && user_reputation.days_undecayed(current_time) > 0,
+        );
+        let Some(mut user_reputation) = user_reputation else {
+            summary.outcome.record(false);
+            continue;
+        };
+
+        let previous_score = user_reputation.total_score;
+        let points_decayed = user_reputation.apply_decay(config, current_time);
+
+        // Keep the on-chain leaderboards in sync with the new scores
+        ctx.accounts.leaderboards.record(&user_reputation, previous_score, current_time)?;
+        user_reputation.exit(&crate::ID)?;
+
+        summary.total_points_decayed = summary.total_points_decayed.saturating_add(points_decayed);
+        summary.outcome.record(true);
+    }
+
+    if let Some(batch_job) = ctx.accounts.batch_job.as_mut() {
+        batch_job.advance(BatchJobKind::Decay, None, start_index, &users, &summary.outcome, current_time)?;
+    }
 
     msg!(
-        "Bulk decay application by admin: {} ({} users)",
+        "Bulk decay by admin: {} ({} users decayed, {} skipped, {} points decayed)",
         ctx.accounts.admin.key(),
-        users.len()
+        summary.outcome.succeeded,
+        summary.outcome.failed,
+        summary.total_points_decayed
     );
 
-    // In a real implementation, you would iterate through each user
-    // and apply decay based on their last activity timestamp
-
-    Ok(())
+    Ok(summary)
 }
 
-/// Bulk award achievements to users
-pub fn bulk_award_achievements(
-    ctx: Context<BulkAwardAchievements>,
+/// Bulk award achievements to users.
+/// `remaining_accounts[i]` must be the writable reputation PDA of `awards[i].user`;
+/// duplicates and awards dated in the future or for a season that has not started yet
+/// are reported as failed without aborting the batch.
+pub fn bulk_award_achievements<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BulkAwardAchievements<'info>>,
     awards: Vec<AchievementAward>,
-) -> Result<()> {
+    start_index: u32,
+) -> Result<BulkOperationResult> {
     let config = &ctx.accounts.config;
+    let current_time = ReputationUtils::get_current_timestamp();
 
     // Validate admin authority
     require!(
@@ -134,24 +250,68 @@ pub fn bulk_award_achievements(
         awards.len() <= 50,
         ReputationError::BulkOperationTooLarge
     );
+    require!(
+        ctx.remaining_accounts.len() == awards.len(),
+        ReputationError::BulkAccountsMismatch
+    );
+
+    let mut result = BulkOperationResult::default();
+
+    for (award, account_info) in awards.iter().zip(ctx.remaining_accounts.iter()) {
+        let user_reputation = if award.awarded_at <= current_time
+            && award.season_id <= config.current_season
+        {
+            ReputationUtils::load_user_reputation(account_info, &award.user)
+        } else {
+            None
+        };
+        let Some(mut user_reputation) =
+            user_reputation.filter(|user_reputation| !user_reputation.has_achievement(award.achievement))
+        else {
+            msg!("Achievement {:?} skipped for user {}", award.achievement, award.user);
+            result.record(false);
+            continue;
+        };
+
+        let previous_score = user_reputation.total_score;
+        user_reputation.award_achievement(award.achievement, award.awarded_at, award.season_id);
+        user_reputation.apply_achievement_bonus(award.achievement, config);
+        user_reputation.last_updated = current_time;
+
+        // Keep the on-chain leaderboards in sync with the new scores
+        ctx.accounts.leaderboards.record(&user_reputation, previous_score, current_time)?;
+        user_reputation.exit(&crate::ID)?;
+
+        result.record(true);
+    }
+
+    if let Some(batch_job) = ctx.accounts.batch_job.as_mut() {
+        batch_job.advance(BatchJobKind::AchievementAward, None, start_index, &awards, &result, current_time)?;
+    }
 
     msg!(
-        "Bulk achievement awards by admin: {} ({} awards)",
+        "Bulk achievement awards by admin: {} ({} awarded, {} skipped)",
         ctx.accounts.admin.key(),
-        awards.len()
+        result.succeeded,
+        result.failed
     );
 
-    // In a real implementation, you would process each achievement award
-
-    Ok(())
+    Ok(result)
 }
 
-/// Reset all seasonal data (admin only)
-pub fn bulk_reset_seasonal_data(
-    ctx: Context<BulkResetSeasonalData>,
+/// Reset all seasonal data (admin only).
+/// `remaining_accounts[i]` must be the writable reputation PDA of `users[i]`;
+/// each user's final rank in the ended season is kept as their best season rank,
+/// and users already closed out for the season are reported as skipped.
+pub fn bulk_reset_seasonal_data<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BulkResetSeasonalData<'info>>,
+    season_id: u32,
     users: Vec<Pubkey>,
-) -> Result<()> {
+    start_index: u32,
+) -> Result<BulkOperationResult> {
     let config = &ctx.accounts.config;
+    let season_data = &ctx.accounts.season_data;
+    let current_time = ReputationUtils::get_current_timestamp();
 
     // Validate admin authority
     require!(
@@ -159,21 +319,52 @@ pub fn bulk_reset_seasonal_data(
         ReputationError::UnauthorizedAdmin
     );
 
+    // Final standings are only settled once the season has ended; points earned
+    // in a later season are left alone, so this can also run after the rollover
+    require!(!season_data.is_active, ReputationError::SeasonStillActive);
+
     // Validate bulk operation size
     require!(
         users.len() <= 200,
         ReputationError::BulkOperationTooLarge
     );
+    require!(
+        ctx.remaining_accounts.len() == users.len(),
+        ReputationError::BulkAccountsMismatch
+    );
+
+    let mut result = BulkOperationResult::default();
+
+    for (user, account_info) in users.iter().zip(ctx.remaining_accounts.iter()) {
+        let Some(mut user_reputation) = ReputationUtils::load_user_reputation(account_info, user) else {
+            msg!("Seasonal reset skipped for user {}", user);
+            result.record(false);
+            continue;
+        };
+
+        // Users already closed out for this season are skipped
+        if !user_reputation.close_season(season_data) {
+            result.record(false);
+            continue;
+        }
+        user_reputation.exit(&crate::ID)?;
+
+        result.record(true);
+    }
+
+    if let Some(batch_job) = ctx.accounts.batch_job.as_mut() {
+        batch_job.advance(BatchJobKind::SeasonalReset, Some(season_id), start_index, &users, &result, current_time)?;
+    }
 
     msg!(
-        "Bulk seasonal reset by admin: {} ({} users)",
+        "Bulk seasonal reset for season {} by admin: {} ({} reset, {} skipped)",
+        season_id,
         ctx.accounts.admin.key(),
-        users.len()
+        result.succeeded,
+        result.failed
     );
 
-    // In a real implementation, you would reset seasonal points for all users
-
-    Ok(())
+    Ok(result)
 }
 
 // AchievementAward is now defined in state.rs - removed duplicate
@@ -187,10 +378,16 @@ pub struct BulkUpdateReputation<'info> {
     )]
     pub config: Account<'info, ReputationConfig>,
 
+    pub leaderboards: LeaderboardAccounts<'info>,
+
     #[account(
         constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
     )]
     pub admin: Signer<'info>,
+
+    /// Batch job this call advances, when the operation is part of one
+    #[account(mut)]
+    pub batch_job: Option<Account<'info, BatchJob>>,
 }
 
 #[derive(Accounts)]
@@ -203,6 +400,12 @@ pub struct BulkInitializeUsers<'info> {
     )]
     pub config: Account<'info, ReputationConfig>,
 
+    #[account(
+        mut,
+        address = config.rank_index @ ReputationError::LeaderboardCalculationFailed
+    )]
+    pub rank_index: AccountLoader<'info, RankIndex>,
+
     #[account(
         mut,
         constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
@@ -210,6 +413,10 @@ pub struct BulkInitializeUsers<'info> {
     pub admin: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Batch job this call advances, when the operation is part of one
+    #[account(mut)]
+    pub batch_job: Option<Account<'info, BatchJob>>,
 }
 
 #[derive(Accounts)]
@@ -221,10 +428,16 @@ pub struct BulkApplyDecay<'info> {
     )]
     pub config: Account<'info, ReputationConfig>,
 
+    pub leaderboards: LeaderboardAccounts<'info>,
+
     #[account(
         constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
     )]
     pub admin: Signer<'info>,
+
+    /// Batch job this call advances, when the operation is part of one
+    #[account(mut)]
+    pub batch_job: Option<Account<'info, BatchJob>>,
 }
 
 #[derive(Accounts)]
@@ -236,14 +449,20 @@ pub struct BulkAwardAchievements<'info> {
     )]
     pub config: Account<'info, ReputationConfig>,
 
+    pub leaderboards: LeaderboardAccounts<'info>,
+
     #[account(
         constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
     )]
     pub admin: Signer<'info>,
+
+    /// Batch job this call advances, when the operation is part of one
+    #[account(mut)]
+    pub batch_job: Option<Account<'info, BatchJob>>,
 }
 
 #[derive(Accounts)]
-#[instruction(users: Vec<Pubkey>)]
+#[instruction(season_id: u32, users: Vec<Pubkey>)]
 pub struct BulkResetSeasonalData<'info> {
     #[account(
         seeds = [b"reputation_config"],
@@ -251,8 +470,19 @@ pub struct BulkResetSeasonalData<'info> {
     )]
     pub config: Account<'info, ReputationConfig>,
 
+    /// Ended season whose final standings are recorded
+    #[account(
+        seeds = [b"season_data", &season_id.to_le_bytes()[..]],
+        bump
+    )]
+    pub season_data: Account<'info, SeasonData>,
+
     #[account(
         constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
     )]
     pub admin: Signer<'info>,
+
+    /// Batch job this call advances, when the operation is part of one
+    #[account(mut)]
+    pub batch_job: Option<Account<'info, BatchJob>>,
 }
\ No newline at end of file
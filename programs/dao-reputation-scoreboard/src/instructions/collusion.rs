@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::*;
+
+/// List flagged pairs for moderator review, most recently flagged first
+pub fn get_flagged_pairs(
+    ctx: Context<GetFlaggedPairs>,
+    page: u32,
+    page_size: u8,
+) -> Result<Vec<FlaggedPair>> {
+    let collusion_registry = &ctx.accounts.collusion_registry;
+
+    // Validate pagination parameters
+    ReputationUtils::validate_pagination(page, page_size)?;
+    require!(
+        page_size <= MAX_FLAGGED_PAGE_SIZE,
+        ReputationError::InvalidPaginationParameters
+    );
+
+    let offset = ReputationUtils::calculate_pagination_offset(page, page_size)?;
+    Ok(collusion_registry.page(offset, page_size as usize))
+}
+
+/// Count an upvote in both users' counterpart summaries
+pub(crate) fn record_upvote_flow(
+    voter_summary: &mut CounterpartSummary,
+    target_summary: &mut CounterpartSummary,
+    voter: Pubkey,
+    target: Pubkey,
+) {
+    // Summaries are created on the users' first vote
+    if voter_summary.user == Pubkey::default() {
+        voter_summary.user = voter;
+    }
+    if target_summary.user == Pubkey::default() {
+        target_summary.user = target;
+    }
+
+    voter_summary.record_upvote_given(target);
+    target_summary.record_upvote_received(voter);
+}
+
+/// Flag an upvote whose flow looks collusive.
+/// Returns the multiplier to apply to its points, in basis points.
+pub(crate) fn screen_collusion(
+    collusion: &CollusionConfig,
+    voter_summary: &CounterpartSummary,
+    collusion_registry: &mut CollusionRegistry,
+    target: Pubkey,
+    current_time: i64,
+) -> u64 {
+    let reason = match collusion.assess(voter_summary, &target) {
+        Some(reason) => reason,
+        None => return 10000,
+    };
+
+    collusion_registry.flag(voter_summary.user, target, reason, voter_summary, current_time);
+    msg!("Vote flagged for collusion: {} on {} ({:?})", voter_summary.user, target, reason);
+
+    match collusion.action {
+        CollusionAction::Dampen => collusion.dampening_multiplier as u64,
+        _ => 10000,
+    }
+}
+
+#[derive(Accounts)]
+pub struct GetFlaggedPairs<'info> {
+    #[account(
+        seeds = [b"collusion_registry"],
+        bump = collusion_registry.bump
+    )]
+    pub collusion_registry: Account<'info, CollusionRegistry>,
+}
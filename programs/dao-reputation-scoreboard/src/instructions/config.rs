@@ -4,6 +4,7 @@ use crate::errors::*;
 use crate::utils::*;
 
 /// Admin function to update system configuration
+#[allow(clippy::manual_range_contains)]
 pub fn update_config(
     ctx: Context<UpdateConfig>,
     new_config: ReputationConfigUpdate,
@@ -69,6 +70,63 @@ pub fn update_config(
         config.decay_enabled = decay_enabled;
     }
 
+    // Existing voting records are resized on their next vote
+    if let Some(vote_history_depth) = new_config.vote_history_depth {
+        ReputationUtils::validate_vote_history_depth(vote_history_depth)?;
+        config.vote_history_depth = vote_history_depth;
+    }
+
+    if let Some(vote_amend_window) = new_config.vote_amend_window {
+        require!(vote_amend_window <= 86400, ReputationError::InvalidConfigurationValues); // Max 24 hours
+        config.vote_amend_window = vote_amend_window;
+    }
+
+    if let Some(pair_daily_vote_limit) = new_config.pair_daily_vote_limit {
+        require!(
+            pair_daily_vote_limit > 0 && pair_daily_vote_limit <= 100,
+            ReputationError::InvalidConfigurationValues
+        );
+        config.pair_daily_vote_limit = pair_daily_vote_limit;
+    }
+
+    if let Some(voting_power) = new_config.voting_power {
+        ReputationUtils::validate_voting_power(&voting_power)?;
+        config.voting_power = voting_power;
+    }
+
+    // Pending commitments are judged by the windows in force when revealed or penalized
+    if let Some(commit_reveal) = new_config.commit_reveal {
+        ReputationUtils::validate_commit_reveal(&commit_reveal)?;
+        config.commit_reveal = commit_reveal;
+    }
+
+    // Applies from the next vote; counts already made this season are kept
+    if let Some(diminishing_returns) = new_config.diminishing_returns {
+        ReputationUtils::validate_diminishing_returns(&diminishing_returns)?;
+        config.diminishing_returns = diminishing_returns;
+    }
+
+    if let Some(collusion) = new_config.collusion {
+        ReputationUtils::validate_collusion(&collusion)?;
+        config.collusion = collusion;
+    }
+
+    if let Some(token_gate) = new_config.token_gate {
+        ReputationUtils::validate_token_gate(&token_gate)?;
+        config.token_gate = token_gate;
+    }
+
+    // Stakes already in escrow keep the mode and mint they were made with
+    if let Some(downvote_stake) = new_config.downvote_stake {
+        ReputationUtils::validate_downvote_stake(&downvote_stake)?;
+        config.downvote_stake = downvote_stake;
+    }
+
+    if let Some(category_voter_rules) = new_config.category_voter_rules {
+        ReputationUtils::validate_category_voter_rules(&category_voter_rules)?;
+        config.category_voter_rules = category_voter_rules;
+    }
+
     config.last_updated = current_time;
 
     msg!("Configuration updated by admin: {}", ctx.accounts.admin.key());
@@ -95,6 +153,17 @@ pub fn get_config(ctx: Context<GetConfig>) -> Result<ReputationConfigView> {
         season_start: config.season_start,
         season_duration: config.season_duration,
         paused: !config.decay_enabled,
+        rank_index: config.rank_index,
+        vote_history_depth: config.vote_history_depth,
+        vote_amend_window: config.vote_amend_window,
+        pair_daily_vote_limit: config.pair_daily_vote_limit,
+        voting_power: config.voting_power,
+        commit_reveal: config.commit_reveal,
+        diminishing_returns: config.diminishing_returns,
+        collusion: config.collusion,
+        token_gate: config.token_gate,
+        downvote_stake: config.downvote_stake,
+        category_voter_rules: config.category_voter_rules,
     };
 
     Ok(config_view)
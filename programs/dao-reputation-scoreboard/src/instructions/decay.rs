@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
 use crate::utils::*;
+use crate::instructions::leaderboard::*;
 
 /// Apply reputation decay for inactive users
 pub fn apply_reputation_decay(
@@ -25,56 +26,26 @@ pub fn apply_reputation_decay(
         ReputationError::NoActivityToDecay
     );
 
-    // Calculate days since last activity
-    let days_inactive = (current_time - user_reputation.last_activity) / 86400;
+    // Calculate inactive days not decayed yet
+    let days_undecayed = user_reputation.days_undecayed(current_time);
     
-    // Only apply decay if user has been inactive for at least 1 day
+    // Only apply decay if at least 1 inactive day has not been decayed yet
     require!(
-        days_inactive > 0,
+        days_undecayed > 0,
         ReputationError::NoActivityToDecay
     );
 
-    // Calculate decay factor
-    let decay_factor = ReputationUtils::calculate_decay_factor(
-        user_reputation.last_activity,
-        current_time,
-        config.decay_rate,
-    );
-
-    // Apply decay to all category points
-    let mut total_decayed = 0u64;
-    let mut new_category_points = user_reputation.category_points;
-    let mut new_raw_votes = user_reputation.raw_votes;
-
-    for (i, points) in new_category_points.iter_mut().enumerate() {
-        let original_points = *points;
-        *points = (original_points * decay_factor) / 10000;
-        total_decayed += original_points - *points;
-        
-        // Update raw votes proportionally
-        new_raw_votes[i] = (new_raw_votes[i] * decay_factor) / 10000;
-    }
-
-    // Update the user reputation with new values
-    user_reputation.category_points = new_category_points;
-    user_reputation.raw_votes = new_raw_votes;
+    // Apply decay to all category points, then rescore
+    let previous_score = user_reputation.total_score;
+    let total_decayed = user_reputation.apply_decay(config, current_time);
 
-    // Recalculate total score with new category points
-    user_reputation.calculate_total_score(&config.category_weights);
-
-    // Update role level based on new score
-    user_reputation.role_level = ReputationUtils::calculate_role_level(
-        user_reputation.total_score,
-        &config.role_thresholds,
-    );
-
-    // Update last updated timestamp
-    user_reputation.last_updated = current_time;
+    // Keep the on-chain leaderboards in sync with the new scores
+    ctx.accounts.leaderboards.record(user_reputation, previous_score, current_time)?;
 
     msg!(
-        "Reputation decay applied to user {}: {} days inactive, {} points decayed",
+        "Reputation decay applied to user {}: {} inactive days decayed, {} points decayed",
         user,
-        days_inactive,
+        days_undecayed,
         total_decayed
     );
 
@@ -98,7 +69,7 @@ pub fn calculate_decay_preview(
 
     let decay_factor = if days_inactive > 0 && config.decay_enabled {
         ReputationUtils::calculate_decay_factor(
-            user_reputation.last_activity,
+            user_reputation.decay_since(),
             current_time,
             config.decay_rate,
         )
@@ -217,7 +188,6 @@ pub fn get_decay_status(
     Ok(decay_statuses)
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 // pub struct DecayPreview {
 //     pub user: Pubkey,
 //     pub current_total_score: u64,
@@ -248,6 +218,8 @@ pub struct ApplyReputationDecay<'info> {
     )]
     pub user_reputation: Account<'info, UserReputation>,
 
+    pub leaderboards: LeaderboardAccounts<'info>,
+
     #[account(
         constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
     )]
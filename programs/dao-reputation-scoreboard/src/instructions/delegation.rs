@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{System};
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::*;
+
+/// Let a trusted member vote with the delegator's reputation in the given categories.
+/// Delegating to the same member again replaces the categories and expiry.
+pub fn delegate_votes(
+    ctx: Context<DelegateVotes>,
+    categories: [bool; 4],
+    expires_at: i64,
+) -> Result<()> {
+    let delegation = &mut ctx.accounts.delegation;
+    let current_time = ReputationUtils::get_current_timestamp();
+
+    require!(
+        ctx.accounts.delegator.key() != ctx.accounts.delegate.key(),
+        ReputationError::CannotDelegateToSelf
+    );
+    require!(
+        categories.iter().any(|delegated| *delegated)
+            && (expires_at == 0 || expires_at > current_time),
+        ReputationError::InvalidDelegation
+    );
+
+    delegation.delegator = ctx.accounts.delegator.key();
+    delegation.delegate = ctx.accounts.delegate.key();
+    delegation.categories = categories;
+    delegation.expires_at = expires_at;
+    delegation.updated_at = current_time;
+    delegation.bump = ctx.bumps.delegation;
+
+    msg!(
+        "Votes delegated: {} to {} in categories {:?} until {}",
+        delegation.delegator,
+        delegation.delegate,
+        categories,
+        expires_at
+    );
+
+    Ok(())
+}
+
+/// Revoke a delegation and reclaim its rent
+pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+    msg!(
+        "Delegation revoked: {} from {}",
+        ctx.accounts.delegator.key(),
+        ctx.accounts.delegate.key()
+    );
+
+    Ok(())
+}
+
+/// Check that `authority` may vote as `voter` in `category`, either as the voter
+/// itself or through an active delegation
+pub(crate) fn authorize_voter(
+    voter: &Pubkey,
+    authority: &Pubkey,
+    delegation: Option<&Delegation>,
+    category: ReputationCategory,
+    current_time: i64,
+) -> Result<()> {
+    if authority == voter {
+        return Ok(());
+    }
+
+    let delegation = delegation.ok_or(ReputationError::DelegationRequired)?;
+    require!(
+        delegation.allows(category, current_time),
+        ReputationError::DelegationNotActive
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DelegateVotes<'info> {
+    /// Only registered members can delegate
+    #[account(
+        seeds = [b"user_reputation", delegator.key().as_ref()],
+        bump
+    )]
+    pub delegator_reputation: Account<'info, UserReputation>,
+
+    /// Only registered members can be delegated to
+    #[account(
+        seeds = [b"user_reputation", delegate.key().as_ref()],
+        bump
+    )]
+    pub delegate_reputation: Account<'info, UserReputation>,
+
+    #[account(
+        init_if_needed,
+        payer = delegator,
+        space = Delegation::LEN,
+        seeds = [b"delegation", delegator.key().as_ref(), delegate.key().as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    /// CHECK: Delegate user account, validated through reputation account
+    pub delegate: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(
+        mut,
+        close = delegator,
+        seeds = [b"delegation", delegator.key().as_ref(), delegate.key().as_ref()],
+        bump = delegation.bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    /// CHECK: Delegate user account, validated through the delegation seeds
+    pub delegate: AccountInfo<'info>,
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
 use crate::utils::*;
+use crate::instructions::leaderboard::*;
 
 /// Export user reputation as a portable certificate
 pub fn export_reputation(ctx: Context<ExportReputation>) -> Result<ReputationCertificate> {
@@ -80,39 +81,42 @@ pub fn export_leaderboard(
         ReputationError::UnauthorizedAdmin
     );
 
-    // In a production system, this would query and aggregate user data
-    // For demonstration, returning mock data
-    let mock_leaderboard = vec![
-        LeaderboardEntry {
-            user: Pubkey::default(),
-            score: 10000,
-            category: ReputationCategory::Governance,
-            rank: 1,
-        },
-        LeaderboardEntry {
-            user: Pubkey::default(),
-            score: 8500,
-            category: ReputationCategory::Development,
-            rank: 2,
-        },
-    ];
+    let leaderboard = ctx.accounts.leaderboard.load()?;
+
+    // The supplied board must rank the requested category
+    require!(
+        leaderboard.scope == Leaderboard::scope_for(category),
+        ReputationError::InvalidReputationCategory
+    );
+
+    // Export the top of the board, bounded by the return data limit
+    let exported_entries = leaderboard.page(0, MAX_PAGE_SIZE as usize);
 
     msg!(
         "Leaderboard exported - Category: {:?}, Season: {:?}, Entries: {}",
         category,
         season_id,
-        mock_leaderboard.len()
+        exported_entries.len()
     );
 
-    Ok(mock_leaderboard)
+    Ok(exported_entries)
 }
 
-/// Import reputation data from external source (admin only)
+/// Maximum Merkle proof length accepted by a claim (trees of up to 2^20 records)
+pub const MAX_IMPORT_PROOF_LENGTH: usize = 20;
+
+/// Commit a Merkle root of legacy `BulkReputationUpdate` records (admin only).
+/// Each record is then applied once through `claim_imported_reputation`.
+/// Claims are tracked per user, so the tree must hold at most one record per user:
+/// a second record for the same user can never be claimed.
 pub fn import_reputation_data(
     ctx: Context<ImportReputationData>,
-    import_data: Vec<BulkReputationUpdate>,
+    migration_id: u32,
+    merkle_root: [u8; 32],
+    record_count: u32,
 ) -> Result<()> {
     let config = &ctx.accounts.config;
+    let current_time = ReputationUtils::get_current_timestamp();
 
     // Validate admin authority
     require!(
@@ -120,21 +124,112 @@ pub fn import_reputation_data(
         ReputationError::UnauthorizedAdmin
     );
 
-    // Validate import size
+    // Every migrated user needs a user slot
     require!(
-        import_data.len() <= 100,
-        ReputationError::BulkOperationTooLarge
+        record_count > 0 && record_count <= 10000, // Maximum users limit
+        ReputationError::InvalidConfigurationValues
     );
 
-    for _update in import_data.iter() {
-        // Validate each update
-        // Skip validation for fixed-size byte array
-    }
+    ctx.accounts.migration.set_inner(ReputationMigration {
+        migration_id,
+        merkle_root,
+        record_count,
+        claimed_count: 0,
+        created_by: ctx.accounts.admin.key(),
+        created_at: current_time,
+        bump: ctx.bumps.migration,
+    });
 
     msg!(
-        "Reputation data import initiated by admin: {} ({} records)",
+        "Reputation migration {} committed by admin: {} ({} records)",
+        migration_id,
         ctx.accounts.admin.key(),
-        import_data.len()
+        record_count
+    );
+
+    Ok(())
+}
+
+/// Apply one committed import record, creating the user's reputation account if needed.
+/// Anyone can claim a record for a new user, but only the user or the admin can overwrite
+/// an existing account. The claim receipt makes each record apply exactly once.
+pub fn claim_imported_reputation(
+    ctx: Context<ClaimImportedReputation>,
+    _migration_id: u32,
+    record: BulkReputationUpdate,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let current_time = ReputationUtils::get_current_timestamp();
+    let migration = &mut ctx.accounts.migration;
+
+    require!(
+        proof.len() <= MAX_IMPORT_PROOF_LENGTH,
+        ReputationError::InvalidMerkleProof
+    );
+    require!(
+        ReputationUtils::verify_merkle_proof(
+            ReputationUtils::import_leaf_hash(&record),
+            &proof,
+            &migration.merkle_root,
+        ),
+        ReputationError::InvalidMerkleProof
+    );
+    require!(
+        migration.claimed_count < migration.record_count,
+        ReputationError::MigrationFullyClaimed
+    );
+    require!(
+        record.role_level <= 5,
+        ReputationError::InvalidConfigurationValues
+    );
+
+    let config = &mut ctx.accounts.config;
+    let user_reputation = &mut ctx.accounts.user_reputation;
+
+    // Users without an account yet are registered as part of the claim
+    if user_reputation.user == Pubkey::default() {
+        require!(
+            config.total_users < 10000, // Maximum users limit
+            ReputationError::MaxUsersLimitReached
+        );
+
+        user_reputation.set_inner(UserReputation::new(record.user, current_time));
+
+        // Every user enters the rank index with a zero score
+        ctx.accounts.leaderboards.rank_index.load_mut()?.insert(0, current_time)?;
+        config.total_users += 1;
+    } else {
+        // Existing reputation is only replaced with the owner's or the admin's consent
+        require!(
+            ctx.accounts.claimer.key() == record.user || ctx.accounts.claimer.key() == config.admin,
+            ReputationError::UnauthorizedImportClaim
+        );
+    }
+
+    let previous_score = user_reputation.total_score;
+    user_reputation.apply_import(
+        &record,
+        &config.category_weights,
+        config.current_season,
+        current_time,
+    );
+
+    // Keep the on-chain leaderboards in sync with the imported scores
+    ctx.accounts.leaderboards.record(user_reputation, previous_score, current_time)?;
+
+    ctx.accounts.import_claim.set_inner(ImportClaim {
+        migration: migration.key(),
+        user: record.user,
+        claimed_at: current_time,
+        bump: ctx.bumps.import_claim,
+    });
+    migration.claimed_count += 1;
+
+    msg!(
+        "Imported reputation claimed for user {} from migration {}: {} points",
+        record.user,
+        migration.migration_id,
+        user_reputation.total_score
     );
 
     Ok(())
@@ -168,6 +263,9 @@ pub struct ExportLeaderboard<'info> {
     )]
     pub config: Account<'info, ReputationConfig>,
 
+    /// Overall or category board, checked against the requested category
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+
     #[account(
         constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
     )]
@@ -175,7 +273,7 @@ pub struct ExportLeaderboard<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(import_data: Vec<BulkReputationUpdate>)]
+#[instruction(migration_id: u32)]
 pub struct ImportReputationData<'info> {
     #[account(
         seeds = [b"reputation_config"],
@@ -184,7 +282,64 @@ pub struct ImportReputationData<'info> {
     pub config: Account<'info, ReputationConfig>,
 
     #[account(
+        init,
+        payer = admin,
+        space = ReputationMigration::LEN,
+        seeds = [b"reputation_migration", &migration_id.to_le_bytes()[..]],
+        bump
+    )]
+    pub migration: Account<'info, ReputationMigration>,
+
+    #[account(
+        mut,
         constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
     )]
     pub admin: Signer<'info>,
-}
\ No newline at end of file
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(migration_id: u32, record: BulkReputationUpdate)]
+pub struct ClaimImportedReputation<'info> {
+    #[account(
+        mut,
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reputation_migration", &migration_id.to_le_bytes()[..]],
+        bump = migration.bump
+    )]
+    pub migration: Account<'info, ReputationMigration>,
+
+    /// Created here, so a second claim of the same record fails
+    #[account(
+        init,
+        payer = claimer,
+        space = ImportClaim::LEN,
+        seeds = [b"import_claim", migration.key().as_ref(), record.user.as_ref()],
+        bump
+    )]
+    pub import_claim: Account<'info, ImportClaim>,
+
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = UserReputation::LEN,
+        seeds = [b"user_reputation", record.user.as_ref()],
+        bump
+    )]
+    pub user_reputation: Account<'info, UserReputation>,
+
+    pub leaderboards: LeaderboardAccounts<'info>,
+
+    /// The user, the admin or a crank paying for the claim
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -4,6 +4,7 @@ use crate::errors::*;
 use crate::utils::*;
 
 /// Initialize the reputation system with admin configuration
+#[allow(clippy::too_many_arguments)]
 pub fn initialize_reputation_system(
     ctx: Context<InitializeReputationSystem>,
     voting_cooldown: u64,
@@ -12,6 +13,7 @@ pub fn initialize_reputation_system(
     min_reputation_to_vote: u64,
     category_weights: [u16; 4],
     role_thresholds: [u64; 5],
+    vote_history_depth: u8,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
     let current_time = ReputationUtils::get_current_timestamp();
@@ -19,6 +21,7 @@ pub fn initialize_reputation_system(
     // Validate configuration parameters
     ReputationUtils::validate_category_weights(&category_weights)?;
     ReputationUtils::validate_role_thresholds(&role_thresholds)?;
+    ReputationUtils::validate_vote_history_depth(vote_history_depth)?;
     
     // Validate other parameters
     require!(voting_cooldown >= 300, ReputationError::InvalidConfigurationValues); // Min 5 minutes
@@ -37,15 +40,32 @@ pub fn initialize_reputation_system(
     config.category_weights = category_weights;
     config.role_thresholds = role_thresholds;
     config.current_season = 1;
-    config.season_start = current_time;
+    config.season_start = 0; // season 1 has no SeasonData until start_new_season
     config.season_duration = 2592000; // 30 days default
     config.total_users = 0;
     config.decay_rate = 10; // 0.1% per day default
     config.decay_enabled = true;
     config.initialized_at = current_time;
     config.last_updated = current_time;
+    config.rank_index = Pubkey::default();
+    config.vote_history_depth = vote_history_depth;
+    config.vote_amend_window = 3600; // 1 hour default
+    config.pair_daily_vote_limit = daily_vote_limit.min(3); // 3 votes per target default
+    config.voting_power = VotingPowerConfig::flat();
+    config.commit_reveal = CommitRevealConfig::disabled();
+    config.diminishing_returns = DiminishingReturnsConfig::disabled();
+    config.collusion = CollusionConfig::flagging();
+    config.token_gate = TokenGateConfig::default();
+    config.downvote_stake = DownvoteStakeConfig::default();
+    config.category_voter_rules = [CategoryVoterRule::default(); 4];
     // No reserved field
 
+    // Votes are screened for collusion from the first one, so the registry is created with the config
+    let collusion_registry = &mut ctx.accounts.collusion_registry;
+    collusion_registry.flagged_pairs = Vec::new();
+    collusion_registry.total_flags = 0;
+    collusion_registry.bump = ctx.bumps.collusion_registry;
+
     msg!("Reputation system initialized with admin: {}", ctx.accounts.admin.key());
     
     Ok(())
@@ -61,6 +81,15 @@ pub struct InitializeReputationSystem<'info> {
         bump
     )]
     pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = CollusionRegistry::LEN,
+        seeds = [b"collusion_registry"],
+        bump
+    )]
+    pub collusion_registry: Account<'info, CollusionRegistry>,
     
     #[account(mut)]
     pub admin: Signer<'info>,
@@ -3,92 +3,392 @@ use crate::state::*;
 use crate::errors::*;
 use crate::utils::*;
 
+/// Create the program-wide leaderboard account (admin function)
+pub fn initialize_leaderboard(ctx: Context<InitializeLeaderboard>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let mut leaderboard = ctx.accounts.leaderboard.load_init()?;
+
+    // Validate admin authority
+    require!(
+        ctx.accounts.admin.key() == config.admin,
+        ReputationError::UnauthorizedAdmin
+    );
+
+    leaderboard.count = 0;
+    leaderboard.bump = ctx.bumps.leaderboard;
+    leaderboard.scope = Leaderboard::OVERALL_SCOPE;
+    leaderboard.last_updated = ReputationUtils::get_current_timestamp();
+
+    msg!("Leaderboard initialized by admin: {}", ctx.accounts.admin.key());
+
+    Ok(())
+}
+
+/// Create the leaderboard for a single reputation category (admin function)
+pub fn initialize_category_leaderboard(
+    ctx: Context<InitializeCategoryLeaderboard>,
+    category: ReputationCategory,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let mut leaderboard = ctx.accounts.leaderboard.load_init()?;
+
+    // Validate admin authority
+    require!(
+        ctx.accounts.admin.key() == config.admin,
+        ReputationError::UnauthorizedAdmin
+    );
+
+    leaderboard.count = 0;
+    leaderboard.bump = ctx.bumps.leaderboard;
+    leaderboard.scope = Leaderboard::scope_for(Some(category));
+    leaderboard.last_updated = ReputationUtils::get_current_timestamp();
+
+    msg!(
+        "{:?} leaderboard initialized by admin: {}",
+        category,
+        ctx.accounts.admin.key()
+    );
+
+    Ok(())
+}
+
+/// Create the rank index backing exact user rankings (admin function)
+pub fn initialize_rank_index(ctx: Context<InitializeRankIndex>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let mut rank_index = ctx.accounts.rank_index.load_init()?;
+
+    // Validate admin authority
+    require!(
+        ctx.accounts.admin.key() == config.admin,
+        ReputationError::UnauthorizedAdmin
+    );
+
+    require!(
+        config.rank_index == Pubkey::default(),
+        ReputationError::RankIndexInitializationNotAllowed
+    );
+
+    // Users registered from now on enter the index at registration; earlier ones are backfilled
+    let current_time = ReputationUtils::get_current_timestamp();
+    rank_index.count = 0;
+    rank_index.last_updated = current_time;
+    rank_index.initialized_at = current_time;
+    rank_index.backfill_remaining = config.total_users;
+    rank_index.backfill_cursor = Pubkey::default();
+    config.rank_index = ctx.accounts.rank_index.key();
+
+    msg!(
+        "Rank index {} initialized by admin: {} ({} users to backfill)",
+        config.rank_index,
+        ctx.accounts.admin.key(),
+        config.total_users
+    );
+
+    Ok(())
+}
+
+/// Add users registered before the rank index existed, passed as remaining accounts
+/// in ascending user order after the backfill cursor (admin function)
+pub fn backfill_rank_index<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BackfillRankIndex<'info>>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let mut rank_index = ctx.accounts.rank_index.load_mut()?;
+    let current_time = ReputationUtils::get_current_timestamp();
+
+    // Validate admin authority
+    require!(
+        ctx.accounts.admin.key() == config.admin,
+        ReputationError::UnauthorizedAdmin
+    );
+
+    // Validate chunk size
+    require!(
+        ctx.remaining_accounts.len() <= 50,
+        ReputationError::BulkOperationTooLarge
+    );
+
+    for account_info in ctx.remaining_accounts.iter() {
+        let user_reputation: Account<'info, UserReputation> = Account::try_from(account_info)?;
+        let (expected_address, _) = Pubkey::find_program_address(
+            &[b"user_reputation", user_reputation.user.as_ref()],
+            &crate::ID,
+        );
+
+        // Ascending order lets the cursor alone mark everyone before it as indexed
+        require!(
+            account_info.key() == expected_address && user_reputation.user > rank_index.backfill_cursor,
+            ReputationError::InvalidBackfillAccount
+        );
+
+        // Users registered after the index was created are already in it
+        if user_reputation.created_at < rank_index.initialized_at {
+            rank_index.insert(user_reputation.total_score, current_time)?;
+            rank_index.backfill_remaining = rank_index.backfill_remaining.saturating_sub(1);
+        }
+        rank_index.backfill_cursor = user_reputation.user;
+    }
+
+    msg!(
+        "Rank index backfilled by admin: {} ({} users remaining)",
+        ctx.accounts.admin.key(),
+        rank_index.backfill_remaining
+    );
+
+    Ok(())
+}
+
 /// Get paginated leaderboard data
 pub fn get_leaderboard(
     ctx: Context<GetLeaderboard>,
-    _category: Option<ReputationCategory>,
+    category: Option<ReputationCategory>,
     page: u32,
     page_size: u8,
 ) -> Result<Vec<LeaderboardEntry>> {
-    let _config = &ctx.accounts.config;
-    
+    let leaderboard = ctx.accounts.leaderboard.load()?;
+
+    // The supplied board must rank the requested category
+    require!(
+        leaderboard.scope == Leaderboard::scope_for(category),
+        ReputationError::InvalidReputationCategory
+    );
+
     // Validate pagination parameters
     ReputationUtils::validate_pagination(page, page_size)?;
-
-    // This is a simplified implementation - in a real scenario, you'd want to
-    // maintain sorted indexes or use external indexing for efficiency
-    let mut leaderboard_entries = Vec::new();
-
-    // For demonstration, we'll return mock data
-    // In production, you'd iterate through all user reputation accounts
-    let mock_entries = vec![
-        LeaderboardEntry {
-            user: Pubkey::default(),
-            score: 5000,
-            category: ReputationCategory::Governance,
-            rank: 1,
-        },
-        LeaderboardEntry {
-            user: Pubkey::default(),
-            score: 4500,
-            category: ReputationCategory::Development,
-            rank: 2,
-        },
-    ];
+    require!(
+        page_size <= MAX_PAGE_SIZE,
+        ReputationError::InvalidPaginationParameters
+    );
 
     // Calculate offset for pagination
     let offset = ReputationUtils::calculate_pagination_offset(page, page_size)?;
-    let end_index = std::cmp::min(offset + page_size as usize, mock_entries.len());
+    let leaderboard_entries = leaderboard.page(offset, page_size as usize);
 
-    if offset < mock_entries.len() {
-        leaderboard_entries.extend_from_slice(&mock_entries[offset..end_index]);
-    }
-
-    msg!("Leaderboard retrieved: page {}, size {}, entries: {}", page, page_size, leaderboard_entries.len());
+    msg!(
+        "Leaderboard retrieved: category {:?}, page {}, size {}, entries: {}",
+        category,
+        page,
+        page_size,
+        leaderboard_entries.len()
+    );
 
     Ok(leaderboard_entries)
 }
 
-/// Calculate and update leaderboard rankings (admin function)
-pub fn update_leaderboard_rankings(ctx: Context<UpdateLeaderboardRankings>) -> Result<()> {
+/// Create the ranking crank account (admin function)
+pub fn initialize_ranking_crank(ctx: Context<InitializeRankingCrank>) -> Result<()> {
     let config = &ctx.accounts.config;
-    
+    let mut ranking_crank = ctx.accounts.ranking_crank.load_init()?;
+
     // Validate admin authority
     require!(
         ctx.accounts.admin.key() == config.admin,
         ReputationError::UnauthorizedAdmin
     );
 
-    // In a production system, this would:
-    // 1. Query all user reputation accounts
-    // 2. Sort by total score
-    // 3. Update rankings
-    // 4. Store top performers in season data
+    ranking_crank.bump = ctx.bumps.ranking_crank;
+    ranking_crank.in_progress = 0;
+
+    msg!("Ranking crank initialized by admin: {}", ctx.accounts.admin.key());
+
+    Ok(())
+}
+
+/// Advance the ranking sweep over the user accounts passed as remaining accounts (permissionless)
+pub fn update_leaderboard_rankings<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateLeaderboardRankings<'info>>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let mut ranking_crank = ctx.accounts.ranking_crank.load_mut()?;
+    let current_time = ReputationUtils::get_current_timestamp();
+
+    // Validate chunk size
+    require!(
+        ctx.remaining_accounts.len() <= MAX_CRANK_BATCH_SIZE,
+        ReputationError::BulkOperationTooLarge
+    );
+
+    // Start a sweep when idle, and restart one whose weights were changed underneath it
+    // or that has run past its timeout without completing
+    if ranking_crank.in_progress == 0
+        || ranking_crank.sweep_weights != config.category_weights
+        || ranking_crank.is_sweep_stale(current_time)
+    {
+        ranking_crank.start_sweep(config.total_users, config.category_weights, current_time);
+    }
+
+    for account_info in ctx.remaining_accounts.iter() {
+        // Ownership and discriminator checks guarantee a genuine user reputation account
+        let mut user_reputation: Account<'info, UserReputation> = Account::try_from(account_info)?;
+        let (expected_address, _) = Pubkey::find_program_address(
+            &[b"user_reputation", user_reputation.user.as_ref()],
+            &crate::ID,
+        );
+        require!(
+            account_info.key() == expected_address && account_info.is_writable,
+            ReputationError::InvalidCrankAccount
+        );
+
+        // Ascending order lets the cursor alone mark everything before it as processed
+        require!(
+            user_reputation.user > ranking_crank.cursor,
+            ReputationError::CrankOutOfOrder
+        );
+
+        let previous_score = user_reputation.total_score;
+        user_reputation.calculate_total_score(&config.category_weights);
+        user_reputation.role_level = ReputationUtils::calculate_role_level(
+            user_reputation.total_score,
+            &config.role_thresholds,
+        );
 
-    msg!("Leaderboard rankings updated by admin: {}", ctx.accounts.admin.key());
+        ctx.accounts.leaderboards.record(&user_reputation, previous_score, current_time)?;
+        user_reputation.exit(&crate::ID)?;
+
+        ranking_crank.cursor = user_reputation.user;
+        // Users registered mid-sweep are scored with current weights from the start
+        if user_reputation.created_at <= ranking_crank.sweep_started_at {
+            ranking_crank.processed += 1;
+        }
+    }
+
+    if ranking_crank.is_sweep_complete() {
+        let leaderboard = ctx.accounts.leaderboards.leaderboard.load()?;
+        ranking_crank.commit_snapshot(&leaderboard, current_time);
+
+        msg!(
+            "Ranking sweep {} completed by {}: snapshot of {} users committed",
+            ranking_crank.completed_sweeps,
+            ctx.accounts.cranker.key(),
+            ranking_crank.snapshot_count
+        );
+    } else {
+        msg!(
+            "Ranking sweep advanced by {}: {} of {} users processed",
+            ctx.accounts.cranker.key(),
+            ranking_crank.processed,
+            ranking_crank.sweep_total
+        );
+    }
 
     Ok(())
 }
 
 /// Get user's current ranking
-pub fn get_user_ranking(ctx: Context<GetUserRanking>) -> Result<u32> {
+pub fn get_user_ranking(ctx: Context<GetUserRanking>) -> Result<UserRanking> {
     let user_reputation = &ctx.accounts.user_reputation;
-    
-    // This would calculate the user's actual rank by comparing with other users
-    // For now, returning a mock rank based on score
-    let mock_rank = if user_reputation.total_score > 5000 {
-        1
-    } else if user_reputation.total_score > 3000 {
-        10
-    } else if user_reputation.total_score > 1000 {
-        50
-    } else {
-        100
+    let rank_index = ctx.accounts.rank_index.load()?;
+
+    // Ranks are only complete once every pre-existing user has been indexed
+    require!(rank_index.is_backfilled(), ReputationError::RankIndexBackfillPending);
+
+    let ranking = UserRanking {
+        user: user_reputation.user,
+        total_score: user_reputation.total_score,
+        rank: rank_index.rank_of(user_reputation.total_score),
+        total_ranked: rank_index.count,
     };
 
-    msg!("User {} ranking: {}", user_reputation.user, mock_rank);
+    msg!(
+        "User {} ranking: {} of {}",
+        ranking.user,
+        ranking.rank,
+        ranking.total_ranked
+    );
 
-    Ok(mock_rank)
+    Ok(ranking)
+}
+
+#[derive(Accounts)]
+pub struct InitializeLeaderboard<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Leaderboard::LEN,
+        seeds = [b"leaderboard"],
+        bump
+    )]
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeRankIndex<'info> {
+    #[account(
+        mut,
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    /// Pre-allocated by the client (RankIndex::LEN bytes, owned by this program)
+    #[account(zero)]
+    pub rank_index: AccountLoader<'info, RankIndex>,
+
+    #[account(
+        constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BackfillRankIndex<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        mut,
+        address = config.rank_index @ ReputationError::LeaderboardCalculationFailed
+    )]
+    pub rank_index: AccountLoader<'info, RankIndex>,
+
+    #[account(
+        constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(category: ReputationCategory)]
+pub struct InitializeCategoryLeaderboard<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Leaderboard::LEN,
+        seeds = [b"category_leaderboard", &[category as u8][..]],
+        bump
+    )]
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
@@ -99,10 +399,103 @@ pub struct GetLeaderboard<'info> {
         bump
     )]
     pub config: Account<'info, ReputationConfig>,
+
+    /// Overall or category board, checked against the requested category
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
 }
 
+/// Overall and per-category leaderboards kept in sync on every points mutation
 #[derive(Accounts)]
-pub struct UpdateLeaderboardRankings<'info> {
+pub struct LeaderboardAccounts<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"leaderboard"],
+        bump
+    )]
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+
+    #[account(
+        mut,
+        seeds = [b"category_leaderboard", &[ReputationCategory::Governance as u8][..]],
+        bump
+    )]
+    pub governance_leaderboard: AccountLoader<'info, Leaderboard>,
+
+    #[account(
+        mut,
+        seeds = [b"category_leaderboard", &[ReputationCategory::Development as u8][..]],
+        bump
+    )]
+    pub development_leaderboard: AccountLoader<'info, Leaderboard>,
+
+    #[account(
+        mut,
+        seeds = [b"category_leaderboard", &[ReputationCategory::Community as u8][..]],
+        bump
+    )]
+    pub community_leaderboard: AccountLoader<'info, Leaderboard>,
+
+    #[account(
+        mut,
+        seeds = [b"category_leaderboard", &[ReputationCategory::Treasury as u8][..]],
+        bump
+    )]
+    pub treasury_leaderboard: AccountLoader<'info, Leaderboard>,
+
+    /// The rank index registered by `initialize_rank_index`
+    #[account(
+        mut,
+        address = config.rank_index @ ReputationError::LeaderboardCalculationFailed
+    )]
+    pub rank_index: AccountLoader<'info, RankIndex>,
+}
+
+impl<'info> LeaderboardAccounts<'info> {
+    /// Category boards in `ReputationCategory` index order
+    fn category_boards(&self) -> [&AccountLoader<'info, Leaderboard>; 4] {
+        [
+            &self.governance_leaderboard,
+            &self.development_leaderboard,
+            &self.community_leaderboard,
+            &self.treasury_leaderboard,
+        ]
+    }
+
+    /// Re-rank a user on every board and in the rank index from their current scores
+    pub fn record(
+        &self,
+        user_reputation: &UserReputation,
+        previous_score: u64,
+        timestamp: i64,
+    ) -> Result<()> {
+        // Users still waiting for the backfill enter the index with their score at that point
+        let mut rank_index = self.rank_index.load_mut()?;
+        if rank_index.tracks(user_reputation) {
+            rank_index.update(previous_score, user_reputation.total_score, timestamp)?;
+        }
+
+        self.leaderboard.load_mut()?.record_score(
+            user_reputation.user,
+            user_reputation.total_score,
+            timestamp,
+        );
+
+        for (board, &points) in self.category_boards().iter().zip(user_reputation.category_points.iter()) {
+            board.load_mut()?.record_score(user_reputation.user, points, timestamp);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializeRankingCrank<'info> {
     #[account(
         seeds = [b"reputation_config"],
         bump
@@ -110,19 +503,63 @@ pub struct UpdateLeaderboardRankings<'info> {
     pub config: Account<'info, ReputationConfig>,
 
     #[account(
+        init,
+        payer = admin,
+        space = RankingCrank::LEN,
+        seeds = [b"ranking_crank"],
+        bump
+    )]
+    pub ranking_crank: AccountLoader<'info, RankingCrank>,
+
+    #[account(
+        mut,
         constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
     )]
     pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateLeaderboardRankings<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"ranking_crank"],
+        bump = ranking_crank.load()?.bump
+    )]
+    pub ranking_crank: AccountLoader<'info, RankingCrank>,
+
+    pub leaderboards: LeaderboardAccounts<'info>,
+
+    /// Anyone may advance the sweep
+    pub cranker: Signer<'info>,
 }
 
 #[derive(Accounts)]
 pub struct GetUserRanking<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
     #[account(
         seeds = [b"user_reputation", user.key().as_ref()],
         bump
     )]
     pub user_reputation: Account<'info, UserReputation>,
 
+    #[account(
+        address = config.rank_index @ ReputationError::LeaderboardCalculationFailed
+    )]
+    pub rank_index: AccountLoader<'info, RankIndex>,
+
     /// CHECK: User account being queried
     pub user: AccountInfo<'info>,
 }
\ No newline at end of file
@@ -10,6 +10,10 @@ pub mod bulk_operations;
 pub mod decay;
 pub mod achievements;
 pub mod streak;
+pub mod batch_job;
+pub mod collusion;
+pub mod stake;
+pub mod delegation;
 
 pub use initialize::*;
 pub use vote::*;
@@ -23,4 +27,8 @@ pub use bulk_operations::*;
 pub use decay::*;
 pub use achievements::*;
 pub use streak::*;
+pub use batch_job::*;
+pub use collusion::*;
+pub use stake::*;
+pub use delegation::*;
 
@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
 use crate::utils::*;
+use crate::instructions::leaderboard::*;
+use anchor_spl::token_interface::TokenAccount;
 
 /// Admin function to manually update user reputation
 pub fn update_user_reputation(
@@ -24,6 +26,7 @@ pub fn update_user_reputation(
     ReputationUtils::validate_string_length(&reason, 200)?;
 
     let category_index = category.to_index();
+    let previous_score = user_reputation.total_score;
 
     // Apply points change
     if points_change >= 0 {
@@ -56,6 +59,9 @@ pub fn update_user_reputation(
     user_reputation.last_updated = current_time;
     user_reputation.last_activity = current_time;
 
+    // Keep the on-chain leaderboards in sync with the new scores
+    ctx.accounts.leaderboards.record(user_reputation, previous_score, current_time)?;
+
     msg!(
         "Admin {} updated reputation for user {} in category {:?}: {} points. Reason: {}",
         ctx.accounts.admin.key(),
@@ -74,22 +80,19 @@ pub fn initialize_user_reputation(ctx: Context<InitializeUserReputation>) -> Res
     let config = &mut ctx.accounts.config;
     let current_time = ReputationUtils::get_current_timestamp();
 
+    if config.token_gate.gates_registration() {
+        ReputationUtils::check_token_gate(
+            &config.token_gate,
+            ctx.accounts.user_token_account.as_deref(),
+            &ctx.accounts.user.key(),
+        )?;
+    }
+
     // Initialize user reputation
-    user_reputation.user = ctx.accounts.user.key();
-    user_reputation.category_points = [0; 4];
-    user_reputation.total_score = 0;
-    user_reputation.raw_votes = [0; 4];
-    user_reputation.role_level = 0;
-    user_reputation.achievements = 0;
-    user_reputation.current_streak = 0;
-    user_reputation.longest_streak = 0;
-    user_reputation.last_activity = current_time;
-    user_reputation.created_at = current_time;
-    user_reputation.last_updated = current_time;
-    user_reputation.seasonal_points = [0; 4];
-    user_reputation.best_season_rank = 0;
-    user_reputation.votes_cast = 0;
-    // No reserved field
+    user_reputation.set_inner(UserReputation::new(ctx.accounts.user.key(), current_time));
+
+    // Every user enters the rank index with a zero score
+    ctx.accounts.rank_index.load_mut()?.insert(0, current_time)?;
 
     // Increment total users count
     config.total_users += 1;
@@ -115,6 +118,8 @@ pub struct UpdateUserReputation<'info> {
     )]
     pub user_reputation: Account<'info, UserReputation>,
 
+    pub leaderboards: LeaderboardAccounts<'info>,
+
     #[account(
         constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
     )]
@@ -142,6 +147,15 @@ pub struct InitializeUserReputation<'info> {
     )]
     pub user_reputation: Account<'info, UserReputation>,
 
+    #[account(
+        mut,
+        address = config.rank_index @ ReputationError::LeaderboardCalculationFailed
+    )]
+    pub rank_index: AccountLoader<'info, RankIndex>,
+
+    /// User's governance token account, required while registration is token-gated
+    pub user_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
     #[account(mut)]
     pub user: Signer<'info>,
 
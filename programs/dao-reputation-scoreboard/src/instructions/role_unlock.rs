@@ -30,13 +30,14 @@ pub fn claim_role_unlock(
     );
 
     // Update user's role level
+    let current_time = ReputationUtils::get_current_timestamp();
     user_reputation.role_level = role_level;
-    user_reputation.last_updated = ReputationUtils::get_current_timestamp();
+    user_reputation.last_updated = current_time;
 
     // Award role achievement if it's a significant milestone
     match role_level {
-        3 => user_reputation.award_achievement(AchievementType::TopContributor),
-        5 => user_reputation.award_achievement(AchievementType::CommunityBuilder),
+        3 => user_reputation.award_achievement(AchievementType::TopContributor, current_time, config.current_season),
+        5 => user_reputation.award_achievement(AchievementType::CommunityBuilder, current_time, config.current_season),
         _ => {}
     }
 
@@ -9,6 +9,7 @@ pub fn start_new_season(
     season_name: String,
     duration_days: u32,
     season_id: u32,
+    leaderboard_size: u16,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
     let season_data = &mut ctx.accounts.season_data;
@@ -24,6 +25,10 @@ pub fn start_new_season(
     ReputationUtils::validate_string_length(&season_name, 50)?;
     require!(duration_days > 0 && duration_days <= 365, ReputationError::SeasonDurationTooLong);
     require!(season_id > config.current_season, ReputationError::InvalidConfigurationValues);
+    require!(
+        leaderboard_size > 0 && leaderboard_size <= MAX_SEASON_LEADERBOARD_SIZE,
+        ReputationError::InvalidConfigurationValues
+    );
 
     // Update config for new season
     config.current_season = season_id;
@@ -35,7 +40,8 @@ pub fn start_new_season(
     season_data.start_time = current_time;
     season_data.end_time = current_time + config.season_duration as i64;
     season_data.is_active = true;
-    season_data.leaderboard = [LeaderboardEntry::default(); 1];
+    season_data.leaderboard_size = leaderboard_size;
+    season_data.leaderboard = Vec::with_capacity(leaderboard_size as usize);
     season_data.total_participants = 0;
     season_data.rewards_distributed = false;
     season_data.total_votes_cast = 0;
@@ -43,10 +49,11 @@ pub fn start_new_season(
     // No reserved field
 
     msg!(
-        "New season started: {} (ID: {}, Duration: {} days)",
+        "New season started: {} (ID: {}, Duration: {} days, Leaderboard size: {})",
         season_name,
         season_id,
-        duration_days
+        duration_days,
+        leaderboard_size
     );
 
     Ok(())
@@ -66,14 +73,18 @@ pub fn end_current_season(ctx: Context<EndCurrentSeason>, _season_id: u32) -> Re
     // Check if season is currently active
     require!(season_data.is_active, ReputationError::SeasonNotActive);
 
-    // Mark season as ended
+    // Mark season as ended; the leaderboard stops accepting points and keeps its final standings
     season_data.is_active = false;
     season_data.end_time = ReputationUtils::get_current_timestamp();
 
     // Award seasonal achievements to top performers
     // This would typically involve iterating through all users and awarding bonuses
 
-    msg!("Season {} ended", season_data.season_id);
+    msg!(
+        "Season {} ended with {} ranked users",
+        season_data.season_id,
+        season_data.leaderboard.len()
+    );
 
     Ok(())
 }
@@ -102,9 +113,47 @@ pub fn get_season_info(ctx: Context<GetSeasonInfo>, _season_id: u32) -> Result<S
     Ok(season_info)
 }
 
+/// Get a page of a season's leaderboard, live or archived
+pub fn get_season_leaderboard(
+    ctx: Context<GetSeasonLeaderboard>,
+    _season_id: u32,
+    page: u32,
+    page_size: u8,
+) -> Result<Vec<LeaderboardEntry>> {
+    let season_data = &ctx.accounts.season_data;
+
+    // Validate pagination parameters
+    ReputationUtils::validate_pagination(page, page_size)?;
+    require!(
+        page_size <= MAX_PAGE_SIZE,
+        ReputationError::InvalidPaginationParameters
+    );
+
+    // Calculate offset for pagination
+    let offset = ReputationUtils::calculate_pagination_offset(page, page_size)?;
+    let leaderboard_entries: Vec<LeaderboardEntry> = season_data
+        .leaderboard
+        .iter()
+        .skip(offset)
+        .take(page_size as usize)
+        .copied()
+        .collect();
+
+    msg!(
+        "Season {} leaderboard retrieved: page {}, size {}, entries: {}",
+        season_data.season_id,
+        page,
+        page_size,
+        leaderboard_entries.len()
+    );
+
+    Ok(leaderboard_entries)
+}
+
 /// Reset user seasonal points (called when new season starts)
-pub fn reset_seasonal_points(ctx: Context<ResetSeasonalPoints>) -> Result<()> {
+pub fn reset_seasonal_points(ctx: Context<ResetSeasonalPoints>, season_id: u32) -> Result<()> {
     let config = &ctx.accounts.config;
+    let season_data = &ctx.accounts.season_data;
     let user_reputation = &mut ctx.accounts.user_reputation;
 
     // Validate admin authority
@@ -113,17 +162,17 @@ pub fn reset_seasonal_points(ctx: Context<ResetSeasonalPoints>) -> Result<()> {
         ReputationError::UnauthorizedAdmin
     );
 
-    // Store previous season best rank if better than current
-    // This would be calculated from actual leaderboard data
-    let current_rank = 100; // Mock rank calculation
-    if user_reputation.best_season_rank == 0 || current_rank < user_reputation.best_season_rank {
-        user_reputation.best_season_rank = current_rank;
-    }
+    // Final standings are only settled once the season has ended; points earned
+    // in a later season are left alone, so this can also run after the rollover
+    require!(!season_data.is_active, ReputationError::SeasonStillActive);
 
-    // Reset seasonal points
-    user_reputation.seasonal_points = [0; 4];
+    // Store the final season rank as best rank if better, then reset seasonal points
+    require!(
+        user_reputation.close_season(season_data),
+        ReputationError::SeasonAlreadyClosed
+    );
 
-    msg!("Seasonal points reset for user: {}", user_reputation.user);
+    msg!("Season {} closed for user: {}", season_id, user_reputation.user);
 
     Ok(())
 }
@@ -131,7 +180,7 @@ pub fn reset_seasonal_points(ctx: Context<ResetSeasonalPoints>) -> Result<()> {
 // SeasonInfo is now defined in state.rs - removed duplicate
 
 #[derive(Accounts)]
-#[instruction(season_name: String, duration_days: u32, season_id: u32)]
+#[instruction(season_name: String, duration_days: u32, season_id: u32, leaderboard_size: u16)]
 pub struct StartNewSeason<'info> {
     #[account(
         mut,
@@ -143,7 +192,7 @@ pub struct StartNewSeason<'info> {
     #[account(
         init,
         payer = admin,
-        space = SeasonData::LEN,
+        space = SeasonData::space(leaderboard_size),
         seeds = [b"season_data", &season_id.to_le_bytes()[..]], // Fixed: Use slice to handle array size
         bump
     )]
@@ -198,6 +247,17 @@ pub struct GetSeasonInfo<'info> {
 }
 
 #[derive(Accounts)]
+#[instruction(season_id: u32)]
+pub struct GetSeasonLeaderboard<'info> {
+    #[account(
+        seeds = [b"season_data", &season_id.to_le_bytes()[..]],
+        bump
+    )]
+    pub season_data: Account<'info, SeasonData>,
+}
+
+#[derive(Accounts)]
+#[instruction(season_id: u32)]
 pub struct ResetSeasonalPoints<'info> {
     #[account(
         seeds = [b"reputation_config"],
@@ -205,6 +265,13 @@ pub struct ResetSeasonalPoints<'info> {
     )]
     pub config: Account<'info, ReputationConfig>,
 
+    /// Ended season whose final standings are recorded
+    #[account(
+        seeds = [b"season_data", &season_id.to_le_bytes()[..]],
+        bump
+    )]
+    pub season_data: Account<'info, SeasonData>,
+
     #[account(
         mut,
         seeds = [b"user_reputation", user.key().as_ref()],
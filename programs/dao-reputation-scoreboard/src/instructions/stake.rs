@@ -0,0 +1,530 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, System};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::*;
+use crate::instructions::leaderboard::*;
+use crate::instructions::vote::rescore_target;
+
+/// Create the vault that holds downvote stakes in escrow (admin only)
+pub fn initialize_stake_vault(ctx: Context<InitializeStakeVault>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let stake_vault = &mut ctx.accounts.stake_vault;
+
+    // Validate admin authority
+    require!(
+        ctx.accounts.admin.key() == config.admin,
+        ReputationError::UnauthorizedAdmin
+    );
+
+    stake_vault.open_stakes = 0;
+    stake_vault.bump = ctx.bumps.stake_vault;
+
+    msg!("Stake vault initialized by admin: {}", ctx.accounts.admin.key());
+
+    Ok(())
+}
+
+/// Create the stake vault's token account for a stake mint (admin only)
+pub fn initialize_stake_token_vault(ctx: Context<InitializeStakeTokenVault>) -> Result<()> {
+    // Validate admin authority
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.config.admin,
+        ReputationError::UnauthorizedAdmin
+    );
+
+    msg!("Stake token vault initialized for mint: {}", ctx.accounts.stake_mint.key());
+
+    Ok(())
+}
+
+/// Appeal a staked downvote within its challenge window (target only)
+pub fn appeal_downvote(ctx: Context<AppealDownvote>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let stake = &mut ctx.accounts.voting_record.downvote_stake;
+    let current_time = ReputationUtils::get_current_timestamp();
+
+    require!(stake.is_held(), ReputationError::NoDownvoteStake);
+    require!(
+        stake.is_challengeable(config.downvote_stake.challenge_window, current_time),
+        ReputationError::ChallengeWindowClosed
+    );
+    require!(!stake.appealed, ReputationError::DownvoteAlreadyAppealed);
+
+    stake.appealed = true;
+
+    msg!(
+        "Downvote appealed: {} on {}",
+        ctx.accounts.voter.key(),
+        ctx.accounts.target.key()
+    );
+
+    Ok(())
+}
+
+/// Resolve a staked downvote (admin only).
+/// An overturned downvote is reversed and its stake slashed; an upheld one's stake goes back to its staker.
+pub fn resolve_downvote(ctx: Context<ResolveDownvote>, overturn: bool) -> Result<()> {
+    let config = &ctx.accounts.config;
+    // Points must reach the season's data once it exists
+    require!(
+        ctx.accounts.season_data.is_some() || !config.season_started(),
+        ReputationError::SeasonDataNotFound
+    );
+    let stake = ctx.accounts.voting_record.downvote_stake;
+    let current_time = ReputationUtils::get_current_timestamp();
+
+    // Validate admin authority
+    require!(
+        ctx.accounts.admin.key() == config.admin,
+        ReputationError::UnauthorizedAdmin
+    );
+    require!(stake.is_held(), ReputationError::NoDownvoteStake);
+
+    let payout_wallet = if overturn {
+        // Reverse the downvote unless the voter already retracted or amended it
+        let target_reputation = &mut ctx.accounts.target_reputation;
+        let previous_score = target_reputation.total_score;
+        if let Some(downvote) = ctx.accounts.voting_record.vote_entry_at_mut(stake.staked_at) {
+            if !downvote.is_upvote && !downvote.retracted {
+                target_reputation.reverse_vote(downvote, config.current_season);
+                downvote.retracted = true;
+            }
+        }
+        rescore_target(
+            config,
+            target_reputation,
+            previous_score,
+            &ctx.accounts.leaderboards,
+            ctx.accounts.season_data.as_deref_mut(),
+            current_time,
+        )?;
+
+        config.downvote_stake.slash_wallet(ctx.accounts.target.key())
+    } else {
+        stake.staker
+    };
+
+    ctx.accounts
+        .downvote_stake
+        .pay_out(&stake, &ctx.accounts.recipient, &payout_wallet)?;
+    ctx.accounts.voting_record.downvote_stake = DownvoteStake::default();
+
+    msg!(
+        "Downvote {}: {} on {}, {} stake paid to {}",
+        if overturn { "overturned" } else { "upheld" },
+        ctx.accounts.voter.key(),
+        ctx.accounts.target.key(),
+        stake.amount,
+        payout_wallet
+    );
+
+    Ok(())
+}
+
+/// Return an unchallenged downvote stake to its staker once its challenge window has passed.
+/// Anyone can call this.
+pub fn release_downvote_stake(ctx: Context<ReleaseDownvoteStake>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let stake = ctx.accounts.voting_record.downvote_stake;
+    let current_time = ReputationUtils::get_current_timestamp();
+
+    require!(stake.is_held(), ReputationError::NoDownvoteStake);
+    require!(!stake.appealed, ReputationError::DownvoteUnderAppeal);
+    require!(
+        !stake.is_challengeable(config.downvote_stake.challenge_window, current_time),
+        ReputationError::ChallengeWindowStillOpen
+    );
+
+    ctx.accounts
+        .downvote_stake
+        .pay_out(&stake, &ctx.accounts.recipient, &stake.staker)?;
+    ctx.accounts.voting_record.downvote_stake = DownvoteStake::default();
+
+    msg!(
+        "Downvote stake released: {} stake returned to {}",
+        stake.amount,
+        stake.staker
+    );
+
+    Ok(())
+}
+
+/// Escrow the configured stake behind a downvote cast at `cast_at`
+pub(crate) fn stake_downvote<'info>(
+    stake_config: &DownvoteStakeConfig,
+    voting_record: &mut VotingRecord,
+    stake_accounts: &mut DownvoteStakeAccounts<'info>,
+    staker: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    cast_at: i64,
+) -> Result<()> {
+    // A pair holds one stake at a time
+    require!(
+        !voting_record.downvote_stake.is_held(),
+        ReputationError::DownvoteStakeOutstanding
+    );
+
+    let stake = DownvoteStake::new(stake_config, staker.key(), cast_at);
+    stake_accounts.escrow(&stake, staker, system_program)?;
+    voting_record.downvote_stake = stake;
+
+    Ok(())
+}
+
+/// Settle the stake behind a downvote cast at `cast_at` that its voter retracted or amended away.
+/// The stake goes back to its staker, or is slashed if the target already appealed the downvote.
+pub(crate) fn withdraw_downvote_stake<'info>(
+    stake_config: &DownvoteStakeConfig,
+    voting_record: &mut VotingRecord,
+    stake_accounts: &mut DownvoteStakeAccounts<'info>,
+    recipient: Option<&AccountInfo<'info>>,
+    target: Pubkey,
+    cast_at: i64,
+) -> Result<()> {
+    let stake = voting_record.downvote_stake;
+    if !stake.is_held() || stake.staked_at != cast_at {
+        return Ok(());
+    }
+
+    let payout_wallet = if stake.appealed {
+        stake_config.slash_wallet(target)
+    } else {
+        stake.staker
+    };
+    let recipient = recipient.ok_or(ReputationError::StakeAccountsMissing)?;
+    stake_accounts.pay_out(&stake, recipient, &payout_wallet)?;
+    voting_record.downvote_stake = DownvoteStake::default();
+
+    msg!(
+        "Downvote stake {}: {} paid to {}",
+        if stake.appealed { "slashed" } else { "refunded" },
+        stake.amount,
+        payout_wallet
+    );
+
+    Ok(())
+}
+
+/// Accounts holding downvote stakes; omitted while downvotes are free
+#[derive(Accounts)]
+pub struct DownvoteStakeAccounts<'info> {
+    #[account(
+        mut,
+        seeds = [b"stake_vault"],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Option<Account<'info, StakeVault>>,
+
+    /// Signer's token account the stake is drawn from, in `Token` mode
+    #[account(mut)]
+    pub voter_stake_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Stake vault's token account for the stake mint, in `Token` mode
+    #[account(mut)]
+    pub token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl<'info> DownvoteStakeAccounts<'info> {
+    /// Move `stake` from the staker into escrow
+    pub fn escrow(
+        &mut self,
+        stake: &DownvoteStake,
+        staker: &Signer<'info>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        let stake_vault = self.stake_vault.as_ref().ok_or(ReputationError::StakeAccountsMissing)?;
+
+        match stake.mode {
+            DownvoteStakeMode::Lamports => system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: staker.to_account_info(),
+                        to: stake_vault.to_account_info(),
+                    },
+                ),
+                stake.amount,
+            )?,
+            DownvoteStakeMode::Token => {
+                let (token_vault, stake_mint, token_program) = self.token_accounts(&stake.mint)?;
+                let voter_stake_account = self
+                    .voter_stake_account
+                    .as_ref()
+                    .ok_or(ReputationError::StakeAccountsMissing)?;
+                token_interface::transfer_checked(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        TransferChecked {
+                            from: voter_stake_account.to_account_info(),
+                            mint: stake_mint.to_account_info(),
+                            to: token_vault.to_account_info(),
+                            authority: staker.to_account_info(),
+                        },
+                    ),
+                    stake.amount,
+                    stake_mint.decimals,
+                )?;
+            }
+            DownvoteStakeMode::Off => return Ok(()),
+        }
+
+        if let Some(stake_vault) = self.stake_vault.as_mut() {
+            stake_vault.open_stakes = stake_vault.open_stakes.saturating_add(1);
+        }
+
+        Ok(())
+    }
+
+    /// Pay `stake` out of escrow to `wallet`: directly for lamports,
+    /// or to a token account owned by `wallet` for tokens
+    pub fn pay_out(
+        &mut self,
+        stake: &DownvoteStake,
+        recipient: &AccountInfo<'info>,
+        wallet: &Pubkey,
+    ) -> Result<()> {
+        let stake_vault = self.stake_vault.as_ref().ok_or(ReputationError::StakeAccountsMissing)?;
+
+        match stake.mode {
+            DownvoteStakeMode::Lamports => {
+                require!(recipient.key() == *wallet, ReputationError::InvalidStakeRecipient);
+                stake_vault.sub_lamports(stake.amount)?;
+                recipient.add_lamports(stake.amount)?;
+            }
+            DownvoteStakeMode::Token => {
+                let (token_vault, stake_mint, token_program) = self.token_accounts(&stake.mint)?;
+                require!(
+                    recipient.owner == &token_program.key(),
+                    ReputationError::InvalidStakeRecipient
+                );
+                let recipient_account =
+                    TokenAccount::try_deserialize(&mut &recipient.try_borrow_data()?[..])?;
+                require!(
+                    recipient_account.owner == *wallet && recipient_account.mint == stake.mint,
+                    ReputationError::InvalidStakeRecipient
+                );
+
+                let signer_seeds: &[&[&[u8]]] = &[&[b"stake_vault", &[stake_vault.bump]]];
+                token_interface::transfer_checked(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        TransferChecked {
+                            from: token_vault.to_account_info(),
+                            mint: stake_mint.to_account_info(),
+                            to: recipient.clone(),
+                            authority: stake_vault.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    stake.amount,
+                    stake_mint.decimals,
+                )?;
+            }
+            DownvoteStakeMode::Off => return Ok(()),
+        }
+
+        if let Some(stake_vault) = self.stake_vault.as_mut() {
+            stake_vault.open_stakes = stake_vault.open_stakes.saturating_sub(1);
+        }
+
+        Ok(())
+    }
+
+    /// Token vault, mint and token program for a stake in `mint`,
+    /// checked against the vault derived for that mint
+    fn token_accounts(
+        &self,
+        mint: &Pubkey,
+    ) -> Result<(
+        &InterfaceAccount<'info, TokenAccount>,
+        &InterfaceAccount<'info, Mint>,
+        &Interface<'info, TokenInterface>,
+    )> {
+        let (token_vault, stake_mint, token_program) =
+            match (&self.token_vault, &self.stake_mint, &self.token_program) {
+                (Some(token_vault), Some(stake_mint), Some(token_program)) => {
+                    (token_vault, stake_mint, token_program)
+                }
+                _ => return err!(ReputationError::StakeAccountsMissing),
+            };
+
+        let (expected_vault, _) =
+            Pubkey::find_program_address(&[b"stake_token_vault", mint.as_ref()], &crate::ID);
+        require!(
+            stake_mint.key() == *mint && token_vault.key() == expected_vault,
+            ReputationError::InvalidStakeAccount
+        );
+
+        Ok((token_vault, stake_mint, token_program))
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializeStakeVault<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = StakeVault::LEN,
+        seeds = [b"stake_vault"],
+        bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeStakeTokenVault<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        seeds = [b"stake_vault"],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"stake_token_vault", stake_mint.key().as_ref()],
+        bump,
+        token::mint = stake_mint,
+        token::authority = stake_vault,
+        token::token_program = token_program
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub stake_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AppealDownvote<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"voting_record", voter.key().as_ref(), target.key().as_ref()],
+        bump
+    )]
+    pub voting_record: Account<'info, VotingRecord>,
+
+    /// CHECK: Voter who cast the downvote, validated through the voting record
+    pub voter: AccountInfo<'info>,
+
+    /// Only the downvoted user can appeal
+    pub target: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDownvote<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"user_reputation", target.key().as_ref()],
+        bump
+    )]
+    pub target_reputation: Account<'info, UserReputation>,
+
+    #[account(
+        mut,
+        seeds = [b"voting_record", voter.key().as_ref(), target.key().as_ref()],
+        bump
+    )]
+    pub voting_record: Account<'info, VotingRecord>,
+
+    pub downvote_stake: DownvoteStakeAccounts<'info>,
+
+    pub leaderboards: LeaderboardAccounts<'info>,
+
+    /// Current season's data, required once a season has been started with `start_new_season`
+    #[account(
+        mut,
+        seeds = [b"season_data", &config.current_season.to_le_bytes()[..]],
+        bump
+    )]
+    pub season_data: Option<Account<'info, SeasonData>>,
+
+    /// CHECK: Receives the stake; the payout wallet, or its token account for token stakes
+    #[account(mut)]
+    pub recipient: AccountInfo<'info>,
+
+    /// CHECK: Voter who cast the downvote, validated through the voting record
+    pub voter: AccountInfo<'info>,
+
+    /// CHECK: Target user account, validated through the voting record
+    pub target: AccountInfo<'info>,
+
+    #[account(
+        constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseDownvoteStake<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"voting_record", voter.key().as_ref(), target.key().as_ref()],
+        bump
+    )]
+    pub voting_record: Account<'info, VotingRecord>,
+
+    pub downvote_stake: DownvoteStakeAccounts<'info>,
+
+    /// CHECK: Receives the stake; the staker, or the staker's token account for token stakes
+    #[account(mut)]
+    pub recipient: AccountInfo<'info>,
+
+    /// CHECK: Voter who cast the downvote, validated through the voting record
+    pub voter: AccountInfo<'info>,
+
+    /// CHECK: Target user account, validated through the voting record
+    pub target: AccountInfo<'info>,
+}
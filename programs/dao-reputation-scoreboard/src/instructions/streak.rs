@@ -2,70 +2,57 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
 use crate::utils::*;
+use crate::instructions::leaderboard::*;
+
+/// Create the streak leaderboard account (admin function)
+pub fn initialize_streak_leaderboard(ctx: Context<InitializeStreakLeaderboard>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let mut streak_leaderboard = ctx.accounts.streak_leaderboard.load_init()?;
+
+    // Validate admin authority
+    require!(
+        ctx.accounts.admin.key() == config.admin,
+        ReputationError::UnauthorizedAdmin
+    );
+
+    streak_leaderboard.current_count = 0;
+    streak_leaderboard.longest_count = 0;
+    streak_leaderboard.bump = ctx.bumps.streak_leaderboard;
+    streak_leaderboard.last_updated = ReputationUtils::get_current_timestamp();
+
+    msg!("Streak leaderboard initialized by admin: {}", ctx.accounts.admin.key());
+
+    Ok(())
+}
 
 /// Update user streak for consecutive participation
 pub fn update_user_streak(
     ctx: Context<UpdateUserStreak>,
     user: Pubkey,
 ) -> Result<()> {
+    let config = &ctx.accounts.config;
     let user_reputation = &mut ctx.accounts.user_reputation;
     let current_time = ReputationUtils::get_current_timestamp();
-    let current_day = current_time / 86400; // Convert to days
-    let last_activity_day = user_reputation.last_activity / 86400;
 
-    let mut streak_bonus = 0u64;
-    let mut newly_awarded_achievements = Vec::new();
-
-    // Calculate streak based on consecutive days of activity
-    if last_activity_day == current_day {
-        // Same day, no streak update needed
+    // Same day, no streak update needed
+    let Some(settlement) = user_reputation.settle_streak(current_time, config.current_season) else {
         return Ok(());
-    } else if last_activity_day == current_day - 1 {
-        // Consecutive day, increment streak
-        user_reputation.current_streak += 1;
-        
-        // Update longest streak if current streak is better
-        if user_reputation.current_streak > user_reputation.longest_streak {
-            user_reputation.longest_streak = user_reputation.current_streak;
-        }
-
-        // Calculate streak bonus
-        streak_bonus = ReputationUtils::calculate_streak_bonus(user_reputation.current_streak);
-
-        // Check for streak achievements
-        if user_reputation.current_streak == 7 && 
-           !user_reputation.has_achievement(AchievementType::WeeklyStreak) {
-            user_reputation.award_achievement(AchievementType::WeeklyStreak);
-            newly_awarded_achievements.push(AchievementType::WeeklyStreak);
-        }
-
-        if user_reputation.current_streak == 30 && 
-           !user_reputation.has_achievement(AchievementType::MonthlyStreak) {
-            user_reputation.award_achievement(AchievementType::MonthlyStreak);
-            newly_awarded_achievements.push(AchievementType::MonthlyStreak);
-        }
-
-    } else if last_activity_day < current_day - 1 {
-        // Streak broken, reset to 1 (current day counts as new streak start)
-        user_reputation.current_streak = 1;
-    }
-
-    // Apply streak bonus to governance category (participation bonus)
-    if streak_bonus > 0 {
-        user_reputation.category_points[0] += streak_bonus;
-        user_reputation.raw_votes[0] += streak_bonus;
-    }
+    };
 
-    // Update activity timestamp
-    user_reputation.last_activity = current_time;
-    user_reputation.last_updated = current_time;
+    // Keep the on-chain leaderboards in sync with the new points (total score is unchanged)
+    let total_score = user_reputation.total_score;
+    ctx.accounts.leaderboards.record(user_reputation, total_score, current_time)?;
+    ctx.accounts
+        .streak_leaderboard
+        .load_mut()?
+        .record_streak(user_reputation, current_time);
 
     msg!(
         "Streak updated for user {}: {} days (bonus: {} points, achievements: {})",
         user,
         user_reputation.current_streak,
-        streak_bonus,
-        newly_awarded_achievements.len()
+        settlement.streak_bonus,
+        settlement.newly_awarded.len()
     );
 
     Ok(())
@@ -128,6 +115,7 @@ pub fn reset_user_streak(
     require!(new_streak <= 365, ReputationError::InvalidConfigurationValues);
 
     let old_streak = user_reputation.current_streak;
+    let current_time = ReputationUtils::get_current_timestamp();
     user_reputation.current_streak = new_streak;
 
     // Update longest streak if applicable
@@ -135,7 +123,13 @@ pub fn reset_user_streak(
         user_reputation.longest_streak = new_streak;
     }
 
-    user_reputation.last_updated = ReputationUtils::get_current_timestamp();
+    user_reputation.last_updated = current_time;
+
+    // Keep the streak leaderboard in sync with the new streak
+    ctx.accounts
+        .streak_leaderboard
+        .load_mut()?
+        .record_streak(user_reputation, current_time);
 
     msg!(
         "Streak reset for user {} by admin {}: {} -> {}",
@@ -150,57 +144,47 @@ pub fn reset_user_streak(
 
 /// Get streak leaderboard (top streaks)
 pub fn get_streak_leaderboard(
-    _ctx: Context<GetStreakLeaderboard>,
+    ctx: Context<GetStreakLeaderboard>,
     leaderboard_type: StreakLeaderboardType,
+    page: u32,
+    page_size: u8,
 ) -> Result<Vec<StreakLeaderboardEntry>> {
-    // In a real implementation, this would query all user reputation accounts
-    // and sort by current_streak or longest_streak
-    
-    let mock_entries = vec![
-        StreakLeaderboardEntry {
-            user: Pubkey::default(),
-            streak_value: 45,
-            is_active: true,
-            current_streak: 45,
-            longest_streak: 67,
-            streak_bonus: (ReputationUtils::calculate_streak_bonus(45) as u32),
-            rank: 1,
-        },
-        StreakLeaderboardEntry {
-            user: Pubkey::default(),
-            streak_value: 32,
-            is_active: true,
-            current_streak: 32,
-            longest_streak: 89,
-            streak_bonus: (ReputationUtils::calculate_streak_bonus(32) as u32),
-            rank: 2,
-        },
-        StreakLeaderboardEntry {
-            user: Pubkey::default(),
-            streak_value: 28,
-            is_active: false,
-            current_streak: 28,
-            longest_streak: 34,
-            streak_bonus: (ReputationUtils::calculate_streak_bonus(28) as u32),
-            rank: 3,
-        },
-    ];
+    let streak_leaderboard = ctx.accounts.streak_leaderboard.load()?;
+    let current_day = ReputationUtils::get_current_timestamp() / 86400;
+
+    // Validate pagination parameters
+    ReputationUtils::validate_pagination(page, page_size)?;
+    require!(
+        page_size <= MAX_STREAK_PAGE_SIZE,
+        ReputationError::InvalidPaginationParameters
+    );
+
+    // Calculate offset for pagination
+    let offset = ReputationUtils::calculate_pagination_offset(page, page_size)?;
+    let entries = streak_leaderboard.page(leaderboard_type, current_day, offset, page_size as usize);
 
     msg!(
-        "Streak leaderboard requested: {:?} ({} entries)",
+        "Streak leaderboard requested: {:?}, page {}, size {} ({} entries)",
         leaderboard_type,
-        mock_entries.len()
+        page,
+        page_size,
+        entries.len()
     );
 
-    Ok(mock_entries)
+    Ok(entries)
 }
 
-/// Bulk update streaks for multiple users (admin function)
-pub fn bulk_update_streaks(
-    ctx: Context<BulkUpdateStreaks>,
+/// Bulk settle streaks for multiple users from their recorded activity (admin function).
+/// `remaining_accounts[i]` must be the writable reputation PDA of `users[i]`;
+/// users with nothing to settle are reported as skipped.
+/// The keeper run is not activity: only days the user was actually active extend a streak.
+pub fn bulk_update_streaks<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BulkUpdateStreaks<'info>>,
     users: Vec<Pubkey>,
-) -> Result<()> {
+    start_index: u32,
+) -> Result<BulkOperationResult> {
     let config = &ctx.accounts.config;
+    let current_time = ReputationUtils::get_current_timestamp();
 
     // Validate admin authority
     require!(
@@ -213,30 +197,110 @@ pub fn bulk_update_streaks(
         users.len() <= 100,
         ReputationError::BulkOperationTooLarge
     );
+    require!(
+        ctx.remaining_accounts.len() == users.len(),
+        ReputationError::BulkAccountsMismatch
+    );
+
+    let mut streak_leaderboard = ctx.accounts.streak_leaderboard.load_mut()?;
+    let mut result = BulkOperationResult::default();
+
+    for (user, account_info) in users.iter().zip(ctx.remaining_accounts.iter()) {
+        let Some(mut user_reputation) = ReputationUtils::load_user_reputation(account_info, user) else {
+            msg!("Streak update skipped for user {}", user);
+            result.record(false);
+            continue;
+        };
+
+        let Some(settlement) = user_reputation.settle_recorded_streak(current_time, config.current_season) else {
+            result.record(false);
+            continue;
+        };
+
+        // Keep the on-chain leaderboards in sync with the new points (total score is unchanged)
+        let total_score = user_reputation.total_score;
+        ctx.accounts.leaderboards.record(&user_reputation, total_score, current_time)?;
+        streak_leaderboard.record_streak(&user_reputation, current_time);
+        if settlement.streak_bonus > 0 || !settlement.newly_awarded.is_empty() {
+            msg!(
+                "Streak settled for user {}: {} days (bonus: {} points, achievements: {})",
+                user,
+                user_reputation.current_streak,
+                settlement.streak_bonus,
+                settlement.newly_awarded.len()
+            );
+        }
+        user_reputation.exit(&crate::ID)?;
+
+        result.record(true);
+    }
+
+    if let Some(batch_job) = ctx.accounts.batch_job.as_mut() {
+        batch_job.advance(BatchJobKind::StreakUpdate, None, start_index, &users, &result, current_time)?;
+    }
 
     msg!(
-        "Bulk streak update initiated by admin: {} ({} users)",
+        "Bulk streak update by admin: {} ({} settled, {} skipped)",
         ctx.accounts.admin.key(),
-        users.len()
+        result.succeeded,
+        result.failed
     );
 
-    // In a real implementation, you would iterate through each user
-    // and update their streak based on their activity
-
-    Ok(())
+    Ok(result)
 }
 
 // StreakLeaderboardType, StreakInfo, and StreakLeaderboardEntry are now defined in state.rs - removed duplicates
 
+#[derive(Accounts)]
+pub struct InitializeStreakLeaderboard<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = StreakLeaderboard::LEN,
+        seeds = [b"streak_leaderboard"],
+        bump
+    )]
+    pub streak_leaderboard: AccountLoader<'info, StreakLeaderboard>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 #[instruction(user: Pubkey)]
 pub struct UpdateUserStreak<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
     #[account(
         mut,
         seeds = [b"user_reputation", user.as_ref()],
         bump
     )]
     pub user_reputation: Account<'info, UserReputation>,
+
+    pub leaderboards: LeaderboardAccounts<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"streak_leaderboard"],
+        bump
+    )]
+    pub streak_leaderboard: AccountLoader<'info, StreakLeaderboard>,
 }
 
 #[derive(Accounts)]
@@ -267,6 +331,13 @@ pub struct ResetUserStreak<'info> {
     )]
     pub user_reputation: Account<'info, UserReputation>,
 
+    #[account(
+        mut,
+        seeds = [b"streak_leaderboard"],
+        bump
+    )]
+    pub streak_leaderboard: AccountLoader<'info, StreakLeaderboard>,
+
     #[account(
         constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
     )]
@@ -274,9 +345,13 @@ pub struct ResetUserStreak<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(leaderboard_type: StreakLeaderboardType)]
-pub struct GetStreakLeaderboard {
-    // No specific accounts needed for leaderboard query
+#[instruction(leaderboard_type: StreakLeaderboardType, page: u32, page_size: u8)]
+pub struct GetStreakLeaderboard<'info> {
+    #[account(
+        seeds = [b"streak_leaderboard"],
+        bump
+    )]
+    pub streak_leaderboard: AccountLoader<'info, StreakLeaderboard>,
 }
 
 #[derive(Accounts)]
@@ -288,8 +363,21 @@ pub struct BulkUpdateStreaks<'info> {
     )]
     pub config: Account<'info, ReputationConfig>,
 
+    pub leaderboards: LeaderboardAccounts<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"streak_leaderboard"],
+        bump
+    )]
+    pub streak_leaderboard: AccountLoader<'info, StreakLeaderboard>,
+
     #[account(
         constraint = admin.key() == config.admin @ ReputationError::UnauthorizedAdmin
     )]
     pub admin: Signer<'info>,
+
+    /// Batch job this call advances, when the operation is part of one
+    #[account(mut)]
+    pub batch_job: Option<Account<'info, BatchJob>>,
 }
\ No newline at end of file
@@ -3,8 +3,14 @@ use anchor_lang::system_program::{System};
 use crate::state::*;
 use crate::errors::*;
 use crate::utils::*;
+use crate::instructions::leaderboard::*;
+use crate::instructions::collusion::*;
+use crate::instructions::stake::*;
+use crate::instructions::delegation::*;
+use anchor_spl::token_interface::TokenAccount;
 
-/// Cast a vote (upvote/downvote) on another user's reputation
+/// Cast a vote (upvote/downvote) on another user's reputation.
+/// A delegate can sign for the voter in the categories the voter delegated to them.
 pub fn cast_vote(
     ctx: Context<CastVote>,
     is_upvote: bool,
@@ -12,15 +18,332 @@ pub fn cast_vote(
     vote_weight: u8,
 ) -> Result<()> {
     let config = &ctx.accounts.config;
-    let voter_reputation = &mut ctx.accounts.voter_reputation;
-    let target_reputation = &mut ctx.accounts.target_reputation;
-    let voting_record = &mut ctx.accounts.voting_record;
+    // Points must reach the season's data once it exists
+    require!(
+        ctx.accounts.season_data.is_some() || !config.season_started(),
+        ReputationError::SeasonDataNotFound
+    );
     let current_time = ReputationUtils::get_current_timestamp();
 
+    // A sealed vote on the target must be revealed before voting on it again
+    require!(
+        !ctx.accounts.voting_record.has_pending_commitment(),
+        ReputationError::CommitmentPending
+    );
+
+    // Votes must be sealed first while commit-reveal mode is on
+    require!(!config.commit_reveal.enabled, ReputationError::CommitRevealRequired);
+
     // Validation checks
+    authorize_voter(
+        &ctx.accounts.voter.key(),
+        &ctx.accounts.authority.key(),
+        ctx.accounts.delegation.as_deref(),
+        category,
+        current_time,
+    )?;
+    require!(
+        ctx.accounts.voter.key() != ctx.accounts.target.key()
+            && ctx.accounts.authority.key() != ctx.accounts.target.key(),
+        ReputationError::CannotVoteOnSelf
+    );
+    ReputationUtils::validate_vote_weight(vote_weight)?;
+    ReputationUtils::check_category_eligibility(
+        &ctx.accounts.voter_reputation,
+        category,
+        &config.category_voter_rules[category.to_index()],
+    )?;
+    if config.token_gate.gates_voting() {
+        ReputationUtils::check_token_gate(
+            &config.token_gate,
+            ctx.accounts.voter_token_account.as_deref(),
+            &ctx.accounts.voter.key(),
+        )?;
+    }
+    check_voter_eligibility(
+        config,
+        &mut ctx.accounts.voter_reputation,
+        &mut ctx.accounts.voting_record,
+        current_time,
+    )?;
+
+    prepare_voting_record(
+        &mut ctx.accounts.voting_record,
+        config,
+        &ctx.accounts.voter,
+        &ctx.accounts.authority,
+        &ctx.accounts.target,
+        &ctx.accounts.system_program,
+        current_time,
+    )?;
+
+    // Track reciprocal upvote flows and screen them for collusion
+    let collusion_multiplier = if is_upvote {
+        record_upvote_flow(
+            &mut ctx.accounts.voter_summary,
+            &mut ctx.accounts.target_summary,
+            ctx.accounts.voter.key(),
+            ctx.accounts.target.key(),
+        );
+        screen_collusion(
+            &config.collusion,
+            &ctx.accounts.voter_summary,
+            &mut ctx.accounts.collusion_registry,
+            ctx.accounts.target.key(),
+            current_time,
+        )
+    } else {
+        10000
+    };
+
+    // Downvotes put the configured stake in escrow until their challenge window passes
+    if !is_upvote && config.downvote_stake.is_required() {
+        stake_downvote(
+            &config.downvote_stake,
+            &mut ctx.accounts.voting_record,
+            &mut ctx.accounts.downvote_stake,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+            current_time,
+        )?;
+    }
+
+    score_vote(
+        config,
+        &ctx.accounts.voter_reputation,
+        &mut ctx.accounts.target_reputation,
+        &mut ctx.accounts.voting_record,
+        &ctx.accounts.leaderboards,
+        ctx.accounts.season_data.as_deref_mut(),
+        is_upvote,
+        category,
+        vote_weight,
+        collusion_multiplier,
+        current_time,
+    )?;
+
+    spend_vote(
+        config,
+        &mut ctx.accounts.voter_reputation,
+        &mut ctx.accounts.voting_record,
+        current_time,
+    );
+
+    msg!(
+        "Vote cast: {} {} on {} in category {:?} with weight {}",
+        ctx.accounts.voter.key(),
+        if is_upvote { "upvoted" } else { "downvoted" },
+        ctx.accounts.target.key(),
+        category,
+        vote_weight
+    );
+    if ctx.accounts.authority.key() != ctx.accounts.voter.key() {
+        msg!("Cast by delegate: {}", ctx.accounts.authority.key());
+    }
+
+    Ok(())
+}
+
+/// Seal a vote on another user as a hash of the vote and a secret salt.
+/// The vote counts against the voter's cooldown and limits now, and scores once revealed.
+pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32]) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let current_time = ReputationUtils::get_current_timestamp();
+
+    require!(config.commit_reveal.enabled, ReputationError::CommitRevealDisabled);
     require!(ctx.accounts.voter.key() != ctx.accounts.target.key(), ReputationError::CannotVoteOnSelf);
+    require!(
+        !ctx.accounts.voting_record.has_pending_commitment(),
+        ReputationError::CommitmentPending
+    );
+    if config.token_gate.gates_voting() {
+        ReputationUtils::check_token_gate(
+            &config.token_gate,
+            ctx.accounts.voter_token_account.as_deref(),
+            &ctx.accounts.voter.key(),
+        )?;
+    }
+    check_voter_eligibility(
+        config,
+        &mut ctx.accounts.voter_reputation,
+        &mut ctx.accounts.voting_record,
+        current_time,
+    )?;
+
+    prepare_voting_record(
+        &mut ctx.accounts.voting_record,
+        config,
+        &ctx.accounts.voter,
+        &ctx.accounts.voter,
+        &ctx.accounts.target,
+        &ctx.accounts.system_program,
+        current_time,
+    )?;
+
+    spend_vote(
+        config,
+        &mut ctx.accounts.voter_reputation,
+        &mut ctx.accounts.voting_record,
+        current_time,
+    );
+
+    let voting_record = &mut ctx.accounts.voting_record;
+    voting_record.pending_commitment = commitment;
+    voting_record.committed_at = current_time;
+    voting_record.committed_season = config.current_season;
+
+    msg!(
+        "Vote committed: {} on {}, revealable from {}",
+        ctx.accounts.voter.key(),
+        ctx.accounts.target.key(),
+        current_time + config.commit_reveal.commit_window as i64
+    );
+
+    Ok(())
+}
+
+/// Reveal a committed vote within the reveal window and score it like `cast_vote`
+pub fn reveal_vote(
+    ctx: Context<RevealVote>,
+    is_upvote: bool,
+    category: ReputationCategory,
+    vote_weight: u8,
+    salt: [u8; 32],
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    // Points must reach the season's data once it exists
+    require!(
+        ctx.accounts.season_data.is_some() || !config.season_started(),
+        ReputationError::SeasonDataNotFound
+    );
+    let voting_record = &mut ctx.accounts.voting_record;
+    let current_time = ReputationUtils::get_current_timestamp();
+
+    require!(voting_record.has_pending_commitment(), ReputationError::NoPendingCommitment);
+    require!(
+        config.commit_reveal.is_reveal_open(voting_record.committed_at, current_time),
+        ReputationError::RevealWindowNotOpen
+    );
+    require!(
+        !config.commit_reveal.is_reveal_expired(voting_record.committed_at, current_time),
+        ReputationError::RevealWindowClosed
+    );
+    require!(
+        ReputationUtils::vote_commitment(
+            &ctx.accounts.voter.key(),
+            &ctx.accounts.target.key(),
+            voting_record.committed_season,
+            is_upvote,
+            category,
+            vote_weight,
+            &salt,
+        ) == voting_record.pending_commitment,
+        ReputationError::CommitmentMismatch
+    );
     ReputationUtils::validate_vote_weight(vote_weight)?;
-    
+    ReputationUtils::check_category_eligibility(
+        &ctx.accounts.voter_reputation,
+        category,
+        &config.category_voter_rules[category.to_index()],
+    )?;
+
+    voting_record.clear_commitment();
+
+    // Track reciprocal upvote flows and screen them for collusion
+    let collusion_multiplier = if is_upvote {
+        record_upvote_flow(
+            &mut ctx.accounts.voter_summary,
+            &mut ctx.accounts.target_summary,
+            ctx.accounts.voter.key(),
+            ctx.accounts.target.key(),
+        );
+        screen_collusion(
+            &config.collusion,
+            &ctx.accounts.voter_summary,
+            &mut ctx.accounts.collusion_registry,
+            ctx.accounts.target.key(),
+            current_time,
+        )
+    } else {
+        10000
+    };
+
+    // Downvotes put the configured stake in escrow until their challenge window passes
+    if !is_upvote && config.downvote_stake.is_required() {
+        stake_downvote(
+            &config.downvote_stake,
+            &mut ctx.accounts.voting_record,
+            &mut ctx.accounts.downvote_stake,
+            &ctx.accounts.voter,
+            &ctx.accounts.system_program,
+            current_time,
+        )?;
+    }
+
+    score_vote(
+        config,
+        &ctx.accounts.voter_reputation,
+        &mut ctx.accounts.target_reputation,
+        &mut ctx.accounts.voting_record,
+        &ctx.accounts.leaderboards,
+        ctx.accounts.season_data.as_deref_mut(),
+        is_upvote,
+        category,
+        vote_weight,
+        collusion_multiplier,
+        current_time,
+    )?;
+
+    msg!(
+        "Vote revealed: {} {} {} in category {:?} with weight {}",
+        ctx.accounts.voter.key(),
+        if is_upvote { "upvoted" } else { "downvoted" },
+        ctx.accounts.target.key(),
+        category,
+        vote_weight
+    );
+
+    Ok(())
+}
+
+/// Penalize a voter whose committed vote expired without being revealed.
+/// Anyone can call this once the reveal window has closed.
+pub fn penalize_unrevealed_vote(ctx: Context<PenalizeUnrevealedVote>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let voter_reputation = &mut ctx.accounts.voter_reputation;
+    let voting_record = &mut ctx.accounts.voting_record;
+    let current_time = ReputationUtils::get_current_timestamp();
+
+    require!(voting_record.has_pending_commitment(), ReputationError::NoPendingCommitment);
+    require!(
+        config.commit_reveal.is_reveal_expired(voting_record.committed_at, current_time),
+        ReputationError::RevealWindowStillOpen
+    );
+
+    let previous_score = voter_reputation.total_score;
+    let penalty = voter_reputation.apply_penalty(config.commit_reveal.unrevealed_penalty, config, current_time);
+    voting_record.clear_commitment();
+
+    // Keep the on-chain leaderboards in sync with the new scores
+    ctx.accounts.leaderboards.record(voter_reputation, previous_score, current_time)?;
+
+    msg!(
+        "Unrevealed vote penalized: {} on {} ({} points deducted)",
+        ctx.accounts.voter.key(),
+        ctx.accounts.target.key(),
+        penalty
+    );
+
+    Ok(())
+}
+
+/// Checks a voter must pass before a vote counts against their limits
+fn check_voter_eligibility(
+    config: &ReputationConfig,
+    voter_reputation: &mut UserReputation,
+    voting_record: &mut VotingRecord,
+    current_time: i64,
+) -> Result<()> {
     // Check voter account age
     ReputationUtils::check_account_age(
         voter_reputation.created_at,
@@ -35,9 +358,15 @@ pub fn cast_vote(
         current_time,
     )?;
 
-    // Check daily vote limit
+    // Check the daily limit on this target
     require!(
-        !voting_record.is_daily_limit_reached(config.daily_vote_limit, current_time),
+        !voting_record.is_daily_limit_reached(config.pair_daily_vote_limit, current_time),
+        ReputationError::PairVoteLimitExceeded
+    );
+
+    // Check the voter's daily budget across all targets
+    require!(
+        !voter_reputation.is_daily_vote_budget_spent(config.daily_vote_limit, current_time),
         ReputationError::DailyVoteLimitExceeded
     );
 
@@ -45,134 +374,800 @@ pub fn cast_vote(
     ReputationUtils::check_minimum_reputation(
         voter_reputation.total_score,
         config.min_reputation_to_vote,
-    )?;
-
-    // Calculate points to add/subtract based on vote weight and type
-    let base_points = vote_weight as u64 * 10; // Base points per vote weight
-    let streak_bonus = ReputationUtils::calculate_streak_bonus(voter_reputation.current_streak);
-    let total_points = base_points + (streak_bonus / 10); // Small streak bonus
-
-    let category_index = category.to_index();
-
-    // Apply vote to target user
-    if is_upvote {
-        target_reputation.raw_votes[category_index] = ReputationUtils::safe_add_points(
-            target_reputation.raw_votes[category_index],
-            total_points,
-        )?;
-        target_reputation.category_points[category_index] = ReputationUtils::safe_add_points(
-            target_reputation.category_points[category_index],
-            total_points,
-        )?;
-    } else {
-        // Downvotes have less impact to prevent abuse
-        let downvote_points = total_points / 2;
-        target_reputation.category_points[category_index] = ReputationUtils::safe_subtract_points(
-            target_reputation.category_points[category_index],
-            downvote_points,
-        )?;
-    }
-
-    // Recalculate total score with weights
-    target_reputation.calculate_total_score(&config.category_weights);
-    
-    // Update role level if changed
-    let new_role_level = ReputationUtils::calculate_role_level(
-        target_reputation.total_score,
-        &config.role_thresholds,
-    );
-    target_reputation.role_level = new_role_level;
-
-    // Update target user activity
-    target_reputation.last_activity = current_time;
-    target_reputation.last_updated = current_time;
-
-    // Update seasonal points
-    target_reputation.seasonal_points[category_index] = ReputationUtils::safe_add_points(
-        target_reputation.seasonal_points[category_index],
-        if is_upvote { total_points } else { 0 },
-    )?;
+    )
+}
 
+/// Initialize a new voting record and follow the configured history depth
+fn prepare_voting_record<'info>(
+    voting_record: &mut Account<'info, VotingRecord>,
+    config: &ReputationConfig,
+    voter: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    current_time: i64,
+) -> Result<()> {
     // Initialize voting record if needed
     if voting_record.voter == Pubkey::default() {
-        voting_record.voter = ctx.accounts.voter.key();
-        voting_record.target = ctx.accounts.target.key();
+        voting_record.voter = voter.key();
+        voting_record.target = target.key();
         voting_record.last_vote = 0;
         voting_record.daily_votes = 0;
         voting_record.last_daily_reset = current_time;
         voting_record.total_votes_on_target = 0;
-        voting_record.vote_history = [VoteHistoryEntry::default(); 1];
+        voting_record.history_depth = config.vote_history_depth;
+        voting_record.vote_history = Vec::new();
         voting_record.history_index = 0;
+        voting_record.clear_commitment();
+        voting_record.counted_season = config.current_season;
+        voting_record.season_category_votes = [0; 4];
         // No reserved field
     }
 
+    // Follow the configured history depth, resizing records created under another one.
+    // The voter owns the record, so rent freed by shrinking it goes back to them
+    if voting_record.history_depth == config.vote_history_depth {
+        return Ok(());
+    }
+    voting_record.resize_history(config.vote_history_depth);
+    ReputationUtils::resize_account(
+        &voting_record.to_account_info(),
+        &payer.to_account_info(),
+        voter,
+        &system_program.to_account_info(),
+        VotingRecord::space(config.vote_history_depth),
+    )
+}
+
+/// Count a vote against the voter's cooldown and daily limits
+fn spend_vote(
+    config: &ReputationConfig,
+    voter_reputation: &mut UserReputation,
+    voting_record: &mut VotingRecord,
+    current_time: i64,
+) {
     // Update voting record
     voting_record.last_vote = current_time;
     voting_record.daily_votes += 1;
     voting_record.total_votes_on_target += 1;
-    voting_record.add_vote_to_history(category, is_upvote, current_time);
 
     // Update voter stats
     voter_reputation.votes_cast += 1;
+    voter_reputation.daily_votes_cast += 1;
     voter_reputation.last_activity = current_time;
 
     // Check for achievements
     if ReputationUtils::should_award_achievement(voter_reputation, AchievementType::FirstVote) {
-        voter_reputation.award_achievement(AchievementType::FirstVote);
+        voter_reputation.award_achievement(AchievementType::FirstVote, current_time, config.current_season);
     }
     if ReputationUtils::should_award_achievement(voter_reputation, AchievementType::ConsistentVoter) {
-        voter_reputation.award_achievement(AchievementType::ConsistentVoter);
+        voter_reputation.award_achievement(AchievementType::ConsistentVoter, current_time, config.current_season);
     }
+}
 
-    msg!(
-        "Vote cast: {} {} on {} in category {:?} with weight {}",
-        ctx.accounts.voter.key(),
-        if is_upvote { "upvoted" } else { "downvoted" },
-        ctx.accounts.target.key(),
+/// Apply a vote's points to the target, rescore it and record the vote in the history
+#[allow(clippy::too_many_arguments)]
+fn score_vote(
+    config: &ReputationConfig,
+    voter_reputation: &UserReputation,
+    target_reputation: &mut UserReputation,
+    voting_record: &mut VotingRecord,
+    leaderboards: &LeaderboardAccounts,
+    mut season_data: Option<&mut SeasonData>,
+    is_upvote: bool,
+    category: ReputationCategory,
+    vote_weight: u8,
+    collusion_multiplier: u64,
+    current_time: i64,
+) -> Result<()> {
+    // Calculate points to add/subtract based on vote weight and type
+    let total_points =
+        ReputationUtils::calculate_vote_points(vote_weight, voter_reputation, &config.voting_power);
+    let total_points = total_points * collusion_multiplier / 10000;
+
+    // Repeated votes on this target and category lose strength over the season
+    let prior_votes = voting_record.season_votes_in(category, config.current_season);
+    let total_points = total_points * config.diminishing_returns.multiplier(prior_votes) / 10000;
+    voting_record.count_season_vote(category, config.current_season);
+
+    let previous_score = target_reputation.total_score;
+
+    // Apply vote to target user (seasonal points included)
+    enter_season(config, target_reputation, season_data.as_deref_mut(), current_time);
+    let points_applied = target_reputation.apply_vote(category, is_upvote, total_points)?;
+
+    // Update target user activity
+    target_reputation.last_activity = current_time;
+
+    if let Some(season_data) = season_data.as_deref_mut() {
+        if season_data.is_accepting_points(current_time) {
+            season_data.total_votes_cast += 1;
+        }
+    }
+
+    rescore_target(config, target_reputation, previous_score, leaderboards, season_data, current_time)?;
+
+    voting_record.add_vote_to_history(VoteHistoryEntry::new(
         category,
-        vote_weight
+        is_upvote,
+        vote_weight,
+        points_applied,
+        current_time,
+        config.current_season,
+    ));
+
+    Ok(())
+}
+
+/// Clear the target's seasonal points from an earlier season before it earns points
+/// in the current one, counting it as a participant the first time it does
+fn enter_season(
+    config: &ReputationConfig,
+    target_reputation: &mut UserReputation,
+    season_data: Option<&mut SeasonData>,
+    current_time: i64,
+) {
+    if !target_reputation.roll_seasonal_points(config.current_season) {
+        return;
+    }
+
+    if let Some(season_data) = season_data {
+        if season_data.is_accepting_points(current_time) {
+            season_data.total_participants += 1;
+        }
+    }
+}
+
+/// Recalculate the target's score and role level after its points changed,
+/// then re-rank it on the leaderboards
+pub(crate) fn rescore_target(
+    config: &ReputationConfig,
+    target_reputation: &mut UserReputation,
+    previous_score: u64,
+    leaderboards: &LeaderboardAccounts,
+    season_data: Option<&mut SeasonData>,
+    current_time: i64,
+) -> Result<()> {
+    // Recalculate total score and role level
+    target_reputation.calculate_total_score(&config.category_weights);
+    target_reputation.role_level = ReputationUtils::calculate_role_level(
+        target_reputation.total_score,
+        &config.role_thresholds,
     );
+    target_reputation.last_updated = current_time;
+
+    // Keep the on-chain leaderboards in sync with the new scores
+    leaderboards.record(target_reputation, previous_score, current_time)?;
+
+    // Rank the target on the current season's leaderboard until the season is frozen;
+    // points left over from an earlier season are not ranked
+    if let Some(season_data) = season_data {
+        if season_data.is_accepting_points(current_time)
+            && target_reputation.points_season == config.current_season
+        {
+            season_data.record_seasonal_points(
+                target_reputation.user,
+                target_reputation.total_seasonal_points(),
+                target_reputation.top_seasonal_category(),
+            );
+        }
+    }
 
     Ok(())
 }
 
-#[derive(Accounts)]
-#[instruction(is_upvote: bool, category: ReputationCategory, vote_weight: u8)]
-pub struct CastVote<'info> {
-    #[account(
-        seeds = [b"reputation_config"],
-        bump
-    )]
-    pub config: Account<'info, ReputationConfig>,
+/// Retract the voter's last vote on the target within the amend window,
+/// reversing exactly the points it applied
+pub fn retract_vote(ctx: Context<RetractVote>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    // Points must reach the season's data once it exists
+    require!(
+        ctx.accounts.season_data.is_some() || !config.season_started(),
+        ReputationError::SeasonDataNotFound
+    );
+    let target_reputation = &mut ctx.accounts.target_reputation;
+    let current_time = ReputationUtils::get_current_timestamp();
 
-    #[account(
-        mut,
-        seeds = [b"user_reputation", voter.key().as_ref()],
-        bump
-    )]
-    pub voter_reputation: Account<'info, UserReputation>,
+    let last_vote = ctx
+        .accounts
+        .voting_record
+        .last_vote_entry_mut()
+        .ok_or(ReputationError::VotingRecordNotFound)?;
+    require!(
+        last_vote.is_amendable(config.vote_amend_window, current_time),
+        ReputationError::VoteAmendWindowExpired
+    );
 
-    #[account(
-        mut,
-        seeds = [b"user_reputation", target.key().as_ref()],
-        bump
-    )]
-    pub target_reputation: Account<'info, UserReputation>,
+    let previous_score = target_reputation.total_score;
+    target_reputation.reverse_vote(last_vote, config.current_season);
+    last_vote.retracted = true;
+    let retracted_vote = *last_vote;
 
-    #[account(
-        init_if_needed,
-        payer = voter,
-        space = VotingRecord::LEN,
-        seeds = [b"voting_record", voter.key().as_ref(), target.key().as_ref()],
-        bump
-    )]
-    pub voting_record: Account<'info, VotingRecord>,
+    // A retracted vote no longer weakens later votes this season
+    if retracted_vote.season_id == config.current_season {
+        ctx.accounts
+            .voting_record
+            .uncount_season_vote(retracted_vote.category, config.current_season);
+    }
+
+    // A retracted downvote no longer needs its stake
+    withdraw_downvote_stake(
+        &config.downvote_stake,
+        &mut ctx.accounts.voting_record,
+        &mut ctx.accounts.downvote_stake,
+        ctx.accounts.stake_recipient.as_deref(),
+        ctx.accounts.target.key(),
+        retracted_vote.timestamp,
+    )?;
+
+    rescore_target(
+        config,
+        target_reputation,
+        previous_score,
+        &ctx.accounts.leaderboards,
+        ctx.accounts.season_data.as_deref_mut(),
+        current_time,
+    )?;
+
+    msg!(
+        "Vote retracted: {} on {} in category {:?} ({} points reversed)",
+        ctx.accounts.voter.key(),
+        ctx.accounts.target.key(),
+        retracted_vote.category,
+        retracted_vote.points
+    );
+
+    Ok(())
+}
+
+/// Replace the voter's last vote on the target within the amend window.
+/// The old vote's points are reversed and the new vote is applied in its place.
+pub fn amend_vote(
+    ctx: Context<AmendVote>,
+    is_upvote: bool,
+    category: ReputationCategory,
+    vote_weight: u8,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    // Points must reach the season's data once it exists
+    require!(
+        ctx.accounts.season_data.is_some() || !config.season_started(),
+        ReputationError::SeasonDataNotFound
+    );
+    let target_reputation = &mut ctx.accounts.target_reputation;
+    let current_time = ReputationUtils::get_current_timestamp();
+
+    // An amendment would be scored in the open, bypassing the sealed commit
+    require!(!config.commit_reveal.enabled, ReputationError::CommitRevealRequired);
+
+    ReputationUtils::validate_vote_weight(vote_weight)?;
+    ReputationUtils::check_category_eligibility(
+        &ctx.accounts.voter_reputation,
+        category,
+        &config.category_voter_rules[category.to_index()],
+    )?;
+    if config.token_gate.gates_voting() {
+        ReputationUtils::check_token_gate(
+            &config.token_gate,
+            ctx.accounts.voter_token_account.as_deref(),
+            &ctx.accounts.voter.key(),
+        )?;
+    }
+
+    let voting_record = &mut ctx.accounts.voting_record;
+    let last_vote = voting_record
+        .last_vote_entry_mut()
+        .copied()
+        .ok_or(ReputationError::VotingRecordNotFound)?;
+    require!(
+        last_vote.is_amendable(config.vote_amend_window, current_time),
+        ReputationError::VoteAmendWindowExpired
+    );
+
+    let previous_score = target_reputation.total_score;
+    target_reputation.reverse_vote(&last_vote, config.current_season);
+
+    // The amended vote takes the old vote's place on the diminishing-returns curve
+    if last_vote.season_id == config.current_season {
+        voting_record.uncount_season_vote(last_vote.category, config.current_season);
+    }
+    let prior_votes = voting_record.season_votes_in(category, config.current_season);
+    voting_record.count_season_vote(category, config.current_season);
+
+    // Amending away from a staked downvote settles its stake, and amending into an unstaked
+    // downvote stakes it like a new one; the stake keeps the vote's cast time
+    if is_upvote {
+        withdraw_downvote_stake(
+            &config.downvote_stake,
+            voting_record,
+            &mut ctx.accounts.downvote_stake,
+            ctx.accounts.stake_recipient.as_deref(),
+            ctx.accounts.target.key(),
+            last_vote.timestamp,
+        )?;
+    } else if config.downvote_stake.is_required()
+        && !(voting_record.downvote_stake.is_held()
+            && voting_record.downvote_stake.staked_at == last_vote.timestamp)
+    {
+        stake_downvote(
+            &config.downvote_stake,
+            voting_record,
+            &mut ctx.accounts.downvote_stake,
+            &ctx.accounts.voter,
+            &ctx.accounts.system_program,
+            last_vote.timestamp,
+        )?;
+    }
+
+    // An upvote is screened for collusion; its flow was counted already if the old vote was one too
+    let collusion_multiplier = if is_upvote {
+        if !last_vote.is_upvote {
+            record_upvote_flow(
+                &mut ctx.accounts.voter_summary,
+                &mut ctx.accounts.target_summary,
+                ctx.accounts.voter.key(),
+                ctx.accounts.target.key(),
+            );
+        }
+        screen_collusion(
+            &config.collusion,
+            &ctx.accounts.voter_summary,
+            &mut ctx.accounts.collusion_registry,
+            ctx.accounts.target.key(),
+            current_time,
+        )
+    } else {
+        10000
+    };
+
+    // The amended vote is valued with the voter's current voting power
+    let total_points = ReputationUtils::calculate_vote_points(
+        vote_weight,
+        &ctx.accounts.voter_reputation,
+        &config.voting_power,
+    );
+    let total_points = total_points * collusion_multiplier / 10000;
+    let total_points = total_points * config.diminishing_returns.multiplier(prior_votes) / 10000;
+    enter_season(
+        config,
+        target_reputation,
+        ctx.accounts.season_data.as_deref_mut(),
+        current_time,
+    );
+    let points_applied = target_reputation.apply_vote(category, is_upvote, total_points)?;
+
+    // The cast time is kept so amending never extends the window
+    if let Some(amended_vote) = voting_record.last_vote_entry_mut() {
+        *amended_vote = VoteHistoryEntry::new(
+            category,
+            is_upvote,
+            vote_weight,
+            points_applied,
+            last_vote.timestamp,
+            config.current_season,
+        );
+    }
+
+    rescore_target(
+        config,
+        target_reputation,
+        previous_score,
+        &ctx.accounts.leaderboards,
+        ctx.accounts.season_data.as_deref_mut(),
+        current_time,
+    )?;
+
+    msg!(
+        "Vote amended: {} {} {} in category {:?} with weight {}",
+        ctx.accounts.voter.key(),
+        if is_upvote { "upvoted" } else { "downvoted" },
+        ctx.accounts.target.key(),
+        category,
+        vote_weight
+    );
+
+    Ok(())
+}
+
+/// Get the vote history between a voter and a target, oldest vote first
+pub fn get_vote_history(
+    ctx: Context<GetVoteHistory>,
+    voter: Pubkey,
+    target: Pubkey,
+) -> Result<VoteHistoryView> {
+    let voting_record = &ctx.accounts.voting_record;
+
+    Ok(VoteHistoryView {
+        voter,
+        target,
+        total_votes_on_target: voting_record.total_votes_on_target,
+        history_depth: voting_record.history_depth,
+        entries: voting_record.chronological_history(),
+    })
+}
+
+#[derive(Accounts)]
+#[instruction(is_upvote: bool, category: ReputationCategory, vote_weight: u8)]
+pub struct CastVote<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"user_reputation", voter.key().as_ref()],
+        bump
+    )]
+    pub voter_reputation: Account<'info, UserReputation>,
+
+    #[account(
+        mut,
+        seeds = [b"user_reputation", target.key().as_ref()],
+        bump
+    )]
+    pub target_reputation: Account<'info, UserReputation>,
+
+    /// Resized to the configured history depth by the instruction
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = VotingRecord::space(config.vote_history_depth),
+        seeds = [b"voting_record", voter.key().as_ref(), target.key().as_ref()],
+        bump
+    )]
+    pub voting_record: Account<'info, VotingRecord>,
+
+    /// Created on the voter's first vote
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CounterpartSummary::LEN,
+        seeds = [b"counterpart_summary", voter.key().as_ref()],
+        bump
+    )]
+    pub voter_summary: Account<'info, CounterpartSummary>,
+
+    /// Created on the target's first received vote
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CounterpartSummary::LEN,
+        seeds = [b"counterpart_summary", target.key().as_ref()],
+        bump
+    )]
+    pub target_summary: Account<'info, CounterpartSummary>,
+
+    #[account(
+        mut,
+        seeds = [b"collusion_registry"],
+        bump = collusion_registry.bump
+    )]
+    pub collusion_registry: Account<'info, CollusionRegistry>,
+
+    pub leaderboards: LeaderboardAccounts<'info>,
+
+    /// Current season's data, required once a season has been started with `start_new_season`
+    #[account(
+        mut,
+        seeds = [b"season_data", &config.current_season.to_le_bytes()[..]],
+        bump
+    )]
+    pub season_data: Option<Account<'info, SeasonData>>,
+
+    /// Voter's governance token account, required while token-gated voting is enabled
+    pub voter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Stake escrow, required for downvotes while downvote staking is enabled
+    pub downvote_stake: DownvoteStakeAccounts<'info>,
+
+    /// Delegation from the voter to the authority, omitted when voters sign for themselves
+    #[account(
+        seeds = [b"delegation", voter.key().as_ref(), authority.key().as_ref()],
+        bump = delegation.bump
+    )]
+    pub delegation: Option<Account<'info, Delegation>>,
+
+    /// CHECK: Voter whose reputation and limits back the vote, validated against the authority.
+    /// Writable so rent freed by shrinking the voting record is refunded to them
+    #[account(mut)]
+    pub voter: AccountInfo<'info>,
+
+    /// Signs and pays for the vote: the voter, or a delegate of the voter
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Target user account, validated through reputation account
+    pub target: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32])]
+pub struct CommitVote<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"user_reputation", voter.key().as_ref()],
+        bump
+    )]
+    pub voter_reputation: Account<'info, UserReputation>,
+
+    /// Only registered users can be voted on
+    #[account(
+        seeds = [b"user_reputation", target.key().as_ref()],
+        bump
+    )]
+    pub target_reputation: Account<'info, UserReputation>,
+
+    /// Resized to the configured history depth by the instruction
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = VotingRecord::space(config.vote_history_depth),
+        seeds = [b"voting_record", voter.key().as_ref(), target.key().as_ref()],
+        bump
+    )]
+    pub voting_record: Account<'info, VotingRecord>,
+
+    /// Voter's governance token account, required while token-gated voting is enabled
+    pub voter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// CHECK: Target user account, validated through reputation account
+    pub target: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(is_upvote: bool, category: ReputationCategory, vote_weight: u8)]
+pub struct RevealVote<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        seeds = [b"user_reputation", voter.key().as_ref()],
+        bump
+    )]
+    pub voter_reputation: Account<'info, UserReputation>,
+
+    #[account(
+        mut,
+        seeds = [b"user_reputation", target.key().as_ref()],
+        bump
+    )]
+    pub target_reputation: Account<'info, UserReputation>,
+
+    #[account(
+        mut,
+        seeds = [b"voting_record", voter.key().as_ref(), target.key().as_ref()],
+        bump
+    )]
+    pub voting_record: Account<'info, VotingRecord>,
+
+    /// Created on the voter's first vote
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = CounterpartSummary::LEN,
+        seeds = [b"counterpart_summary", voter.key().as_ref()],
+        bump
+    )]
+    pub voter_summary: Account<'info, CounterpartSummary>,
+
+    /// Created on the target's first received vote
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = CounterpartSummary::LEN,
+        seeds = [b"counterpart_summary", target.key().as_ref()],
+        bump
+    )]
+    pub target_summary: Account<'info, CounterpartSummary>,
+
+    #[account(
+        mut,
+        seeds = [b"collusion_registry"],
+        bump = collusion_registry.bump
+    )]
+    pub collusion_registry: Account<'info, CollusionRegistry>,
+
+    pub leaderboards: LeaderboardAccounts<'info>,
+
+    /// Current season's data, required once a season has been started with `start_new_season`
+    #[account(
+        mut,
+        seeds = [b"season_data", &config.current_season.to_le_bytes()[..]],
+        bump
+    )]
+    pub season_data: Option<Account<'info, SeasonData>>,
+
+    /// Stake escrow, required for downvotes while downvote staking is enabled
+    pub downvote_stake: DownvoteStakeAccounts<'info>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// CHECK: Target user account, validated through reputation account
+    pub target: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PenalizeUnrevealedVote<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"user_reputation", voter.key().as_ref()],
+        bump
+    )]
+    pub voter_reputation: Account<'info, UserReputation>,
+
+    #[account(
+        mut,
+        seeds = [b"voting_record", voter.key().as_ref(), target.key().as_ref()],
+        bump
+    )]
+    pub voting_record: Account<'info, VotingRecord>,
+
+    pub leaderboards: LeaderboardAccounts<'info>,
+
+    /// CHECK: Voter who committed the vote, validated through the voting record
+    pub voter: AccountInfo<'info>,
+
+    /// CHECK: Target user account, validated through the voting record
+    pub target: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(voter: Pubkey, target: Pubkey)]
+pub struct GetVoteHistory<'info> {
+    #[account(
+        seeds = [b"voting_record", voter.as_ref(), target.as_ref()],
+        bump
+    )]
+    pub voting_record: Account<'info, VotingRecord>,
+}
+
+#[derive(Accounts)]
+pub struct RetractVote<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"user_reputation", target.key().as_ref()],
+        bump
+    )]
+    pub target_reputation: Account<'info, UserReputation>,
+
+    #[account(
+        mut,
+        seeds = [b"voting_record", voter.key().as_ref(), target.key().as_ref()],
+        bump
+    )]
+    pub voting_record: Account<'info, VotingRecord>,
+
+    pub leaderboards: LeaderboardAccounts<'info>,
+
+    /// Current season's data, required once a season has been started with `start_new_season`
+    #[account(
+        mut,
+        seeds = [b"season_data", &config.current_season.to_le_bytes()[..]],
+        bump
+    )]
+    pub season_data: Option<Account<'info, SeasonData>>,
+
+    /// Stake escrow, required when retracting a staked downvote
+    pub downvote_stake: DownvoteStakeAccounts<'info>,
+
+    /// CHECK: Receives a retracted downvote's stake; the staker, or the slash recipient once appealed
+    #[account(mut)]
+    pub stake_recipient: Option<UncheckedAccount<'info>>,
+
+    pub voter: Signer<'info>,
+
+    /// CHECK: Target user account, validated through reputation account
+    pub target: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(is_upvote: bool, category: ReputationCategory, vote_weight: u8)]
+pub struct AmendVote<'info> {
+    #[account(
+        seeds = [b"reputation_config"],
+        bump
+    )]
+    pub config: Account<'info, ReputationConfig>,
+
+    #[account(
+        seeds = [b"user_reputation", voter.key().as_ref()],
+        bump
+    )]
+    pub voter_reputation: Account<'info, UserReputation>,
+
+    #[account(
+        mut,
+        seeds = [b"user_reputation", target.key().as_ref()],
+        bump
+    )]
+    pub target_reputation: Account<'info, UserReputation>,
+
+    #[account(
+        mut,
+        seeds = [b"voting_record", voter.key().as_ref(), target.key().as_ref()],
+        bump
+    )]
+    pub voting_record: Account<'info, VotingRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"counterpart_summary", voter.key().as_ref()],
+        bump
+    )]
+    pub voter_summary: Account<'info, CounterpartSummary>,
+
+    #[account(
+        mut,
+        seeds = [b"counterpart_summary", target.key().as_ref()],
+        bump
+    )]
+    pub target_summary: Account<'info, CounterpartSummary>,
+
+    #[account(
+        mut,
+        seeds = [b"collusion_registry"],
+        bump = collusion_registry.bump
+    )]
+    pub collusion_registry: Account<'info, CollusionRegistry>,
+
+    pub leaderboards: LeaderboardAccounts<'info>,
+
+    /// Current season's data, required once a season has been started with `start_new_season`
+    #[account(
+        mut,
+        seeds = [b"season_data", &config.current_season.to_le_bytes()[..]],
+        bump
+    )]
+    pub season_data: Option<Account<'info, SeasonData>>,
+
+    /// Voter's governance token account, required while token-gated voting is enabled
+    pub voter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Stake escrow, required when amending into or away from a staked downvote
+    pub downvote_stake: DownvoteStakeAccounts<'info>,
+
+    /// CHECK: Receives the stake of a downvote amended away; the staker, or the slash recipient once appealed
+    #[account(mut)]
+    pub stake_recipient: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// CHECK: Target user account, validated through reputation account
+    pub target: AccountInfo<'info>,
 
-    #[account(mut)]
-    pub voter: Signer<'info>,
-    
-    /// CHECK: Target user account, validated through reputation account
-    pub target: AccountInfo<'info>,
-    
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
@@ -1,3 +1,7 @@
+// Anchor 0.31's `#[program]` expands its IDL resize handler at the crate root with the
+// deprecated `AccountInfo::realloc`; lint attributes on the program module cannot reach it
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
 
 pub mod instructions;
@@ -10,7 +14,9 @@ use state::{
     ReputationCategory, AchievementType, LeaderboardEntry, ReputationCertificate, 
     ReputationConfigUpdate, BulkReputationUpdate, SeasonInfo, DecayPreview, 
     DecayStatus, AchievementProgress, StreakInfo, StreakLeaderboardType, 
-    StreakLeaderboardEntry, ReputationConfigView, AchievementAward
+    StreakLeaderboardEntry, ReputationConfigView, AchievementAward, UserRanking,
+    BulkOperationResult, BulkDecaySummary, BatchJobKind, BatchJobParams,
+    VoteHistoryView, FlaggedPair
 };
 
 // Replace this with the output from solana-keygen pubkey command
@@ -22,6 +28,7 @@ pub mod dao_reputation_scoreboard {
     use super::*;
 
         /// Initialize the reputation system with admin configuration
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_reputation_system(
         ctx: Context<InitializeReputationSystem>,
         voting_cooldown: u64,
@@ -30,6 +37,7 @@ pub mod dao_reputation_scoreboard {
         min_reputation_to_vote: u64,
         category_weights: [u16; 4], // [Governance, Development, Community, Treasury]
         role_thresholds: [u64; 5],  // Different role unlock thresholds
+        vote_history_depth: u8,     // Votes kept per voter→target history
     ) -> Result<()> {
         instructions::initialize_reputation_system(
             ctx,
@@ -39,6 +47,7 @@ pub mod dao_reputation_scoreboard {
             min_reputation_to_vote,
             category_weights,
             role_thresholds,
+            vote_history_depth,
         )
     }
 
@@ -52,6 +61,51 @@ pub mod dao_reputation_scoreboard {
         instructions::cast_vote(ctx, is_upvote, category, vote_weight)
     }
 
+    /// Commit a sealed vote on another user while commit-reveal mode is enabled
+    pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32]) -> Result<()> {
+        instructions::commit_vote(ctx, commitment)
+    }
+
+    /// Reveal a committed vote and apply it
+    pub fn reveal_vote(
+        ctx: Context<RevealVote>,
+        is_upvote: bool,
+        category: ReputationCategory,
+        vote_weight: u8,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_vote(ctx, is_upvote, category, vote_weight, salt)
+    }
+
+    /// Penalize a voter whose committed vote expired unrevealed
+    pub fn penalize_unrevealed_vote(ctx: Context<PenalizeUnrevealedVote>) -> Result<()> {
+        instructions::penalize_unrevealed_vote(ctx)
+    }
+
+    /// Retract the last vote on a target within the amend window
+    pub fn retract_vote(ctx: Context<RetractVote>) -> Result<()> {
+        instructions::retract_vote(ctx)
+    }
+
+    /// Amend the last vote on a target within the amend window
+    pub fn amend_vote(
+        ctx: Context<AmendVote>,
+        is_upvote: bool,
+        category: ReputationCategory,
+        vote_weight: u8,
+    ) -> Result<()> {
+        instructions::amend_vote(ctx, is_upvote, category, vote_weight)
+    }
+
+    /// Get the vote history between a voter and a target
+    pub fn get_vote_history(
+        ctx: Context<GetVoteHistory>,
+        voter: Pubkey,
+        target: Pubkey,
+    ) -> Result<VoteHistoryView> {
+        instructions::get_vote_history(ctx, voter, target)
+    }
+
     /// Admin function to manually update user reputation
     pub fn update_user_reputation(
         ctx: Context<UpdateUserReputation>,
@@ -69,6 +123,35 @@ pub mod dao_reputation_scoreboard {
         instructions::initialize_user_reputation(ctx)
     }
 
+    /// Create the on-chain leaderboard (admin only)
+    pub fn initialize_leaderboard(
+        ctx: Context<InitializeLeaderboard>,
+    ) -> Result<()> {
+        instructions::initialize_leaderboard(ctx)
+    }
+
+    /// Create a per-category leaderboard (admin only)
+    pub fn initialize_category_leaderboard(
+        ctx: Context<InitializeCategoryLeaderboard>,
+        category: ReputationCategory,
+    ) -> Result<()> {
+        instructions::initialize_category_leaderboard(ctx, category)
+    }
+
+    /// Create the rank index used for exact user rankings (admin only)
+    pub fn initialize_rank_index(
+        ctx: Context<InitializeRankIndex>,
+    ) -> Result<()> {
+        instructions::initialize_rank_index(ctx)
+    }
+
+    /// Add users registered before the rank index existed (admin only)
+    pub fn backfill_rank_index<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BackfillRankIndex<'info>>,
+    ) -> Result<()> {
+        instructions::backfill_rank_index(ctx)
+    }
+
     /// Get paginated leaderboard data
     pub fn get_leaderboard(
         ctx: Context<GetLeaderboard>,
@@ -93,8 +176,9 @@ pub mod dao_reputation_scoreboard {
         season_name: String,
         duration_days: u32,
         season_id: u32,
+        leaderboard_size: u16,
     ) -> Result<()> {
-        instructions::start_new_season(ctx, season_name, duration_days, season_id)
+        instructions::start_new_season(ctx, season_name, duration_days, season_id, leaderboard_size)
     }
 
     /// Export user reputation as a portable certificate
@@ -113,11 +197,12 @@ pub mod dao_reputation_scoreboard {
     }
 
     /// Bulk admin operation to update multiple users
-    pub fn bulk_update_reputation(
-        ctx: Context<BulkUpdateReputation>,
+    pub fn bulk_update_reputation<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BulkUpdateReputation<'info>>,
         updates: Vec<BulkReputationUpdate>,
-    ) -> Result<()> {
-        instructions::bulk_update_reputation(ctx, updates)
+        start_index: u32,
+    ) -> Result<BulkOperationResult> {
+        instructions::bulk_update_reputation(ctx, updates, start_index)
     }
 
     /// Apply reputation decay for inactive users
@@ -137,6 +222,65 @@ pub mod dao_reputation_scoreboard {
         instructions::award_achievement(ctx, user, achievement_type)
     }
 
+    /// Create the streak leaderboard (admin only)
+    pub fn initialize_streak_leaderboard(
+        ctx: Context<InitializeStreakLeaderboard>,
+    ) -> Result<()> {
+        instructions::initialize_streak_leaderboard(ctx)
+    }
+
+    /// Create the vault holding downvote stakes (admin only)
+    pub fn initialize_stake_vault(
+        ctx: Context<InitializeStakeVault>,
+    ) -> Result<()> {
+        instructions::initialize_stake_vault(ctx)
+    }
+
+    /// Create the stake vault's token account for a stake mint (admin only)
+    pub fn initialize_stake_token_vault(
+        ctx: Context<InitializeStakeTokenVault>,
+    ) -> Result<()> {
+        instructions::initialize_stake_token_vault(ctx)
+    }
+
+    /// Appeal a staked downvote within its challenge window
+    pub fn appeal_downvote(
+        ctx: Context<AppealDownvote>,
+    ) -> Result<()> {
+        instructions::appeal_downvote(ctx)
+    }
+
+    /// Overturn or uphold a staked downvote, slashing or returning its stake (admin only)
+    pub fn resolve_downvote(
+        ctx: Context<ResolveDownvote>,
+        overturn: bool,
+    ) -> Result<()> {
+        instructions::resolve_downvote(ctx, overturn)
+    }
+
+    /// Return an unchallenged downvote stake after its challenge window
+    pub fn release_downvote_stake(
+        ctx: Context<ReleaseDownvoteStake>,
+    ) -> Result<()> {
+        instructions::release_downvote_stake(ctx)
+    }
+
+    /// Let another member vote with your reputation in the given categories
+    pub fn delegate_votes(
+        ctx: Context<DelegateVotes>,
+        categories: [bool; 4],
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::delegate_votes(ctx, categories, expires_at)
+    }
+
+    /// Revoke a vote delegation
+    pub fn revoke_delegation(
+        ctx: Context<RevokeDelegation>,
+    ) -> Result<()> {
+        instructions::revoke_delegation(ctx)
+    }
+
     /// Update user streak for consecutive participation
     pub fn update_user_streak(
         ctx: Context<UpdateUserStreak>,
@@ -161,11 +305,22 @@ pub mod dao_reputation_scoreboard {
         instructions::get_season_info(ctx, season_id)
     }
 
+    /// Get a page of a season's leaderboard
+    pub fn get_season_leaderboard(
+        ctx: Context<GetSeasonLeaderboard>,
+        season_id: u32,
+        page: u32,
+        page_size: u8,
+    ) -> Result<Vec<LeaderboardEntry>> {
+        instructions::get_season_leaderboard(ctx, season_id, page, page_size)
+    }
+
     /// Reset user seasonal points (admin only)
     pub fn reset_seasonal_points(
         ctx: Context<ResetSeasonalPoints>,
+        season_id: u32,
     ) -> Result<()> {
-        instructions::reset_seasonal_points(ctx)
+        instructions::reset_seasonal_points(ctx, season_id)
     }
 
     /// Verify a reputation certificate's authenticity
@@ -176,9 +331,16 @@ pub mod dao_reputation_scoreboard {
         instructions::verify_reputation_certificate(ctx, certificate)
     }
 
-    /// Get leaderboard rankings update
-    pub fn update_leaderboard_rankings(
-        ctx: Context<UpdateLeaderboardRankings>,
+    /// Create the ranking crank (admin only)
+    pub fn initialize_ranking_crank(
+        ctx: Context<InitializeRankingCrank>,
+    ) -> Result<()> {
+        instructions::initialize_ranking_crank(ctx)
+    }
+
+    /// Advance the permissionless ranking sweep over the users in remaining accounts
+    pub fn update_leaderboard_rankings<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateLeaderboardRankings<'info>>,
     ) -> Result<()> {
         instructions::update_leaderboard_rankings(ctx)
     }
@@ -186,7 +348,7 @@ pub mod dao_reputation_scoreboard {
     /// Get user ranking
     pub fn get_user_ranking(
         ctx: Context<GetUserRanking>,
-    ) -> Result<u32> {
+    ) -> Result<UserRanking> {
         instructions::get_user_ranking(ctx)
     }
 
@@ -279,16 +441,46 @@ pub mod dao_reputation_scoreboard {
     pub fn get_streak_leaderboard(
         ctx: Context<GetStreakLeaderboard>,
         leaderboard_type: StreakLeaderboardType,
+        page: u32,
+        page_size: u8,
     ) -> Result<Vec<StreakLeaderboardEntry>> {
-        instructions::get_streak_leaderboard(ctx, leaderboard_type)
+        instructions::get_streak_leaderboard(ctx, leaderboard_type, page, page_size)
     }
 
-    /// Bulk update streaks (admin only)
-    pub fn bulk_update_streaks(
-        ctx: Context<BulkUpdateStreaks>,
+    /// List pairs flagged by collusion detection, most recent first
+    pub fn get_flagged_pairs(
+        ctx: Context<GetFlaggedPairs>,
+        page: u32,
+        page_size: u8,
+    ) -> Result<Vec<FlaggedPair>> {
+        instructions::get_flagged_pairs(ctx, page, page_size)
+    }
+
+    /// Bulk settle streaks from recorded activity (admin only)
+    pub fn bulk_update_streaks<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BulkUpdateStreaks<'info>>,
         users: Vec<Pubkey>,
+        start_index: u32,
+    ) -> Result<BulkOperationResult> {
+        instructions::bulk_update_streaks(ctx, users, start_index)
+    }
+
+    /// Create a resumable batch job for a bulk operation (admin only)
+    pub fn create_batch_job(
+        ctx: Context<CreateBatchJob>,
+        job_id: u64,
+        kind: BatchJobKind,
+        params: BatchJobParams,
     ) -> Result<()> {
-        instructions::bulk_update_streaks(ctx, users)
+        instructions::create_batch_job(ctx, job_id, kind, params)
+    }
+
+    /// Cancel an active batch job (admin only)
+    pub fn cancel_batch_job(
+        ctx: Context<CancelBatchJob>,
+        job_id: u64,
+    ) -> Result<()> {
+        instructions::cancel_batch_job(ctx, job_id)
     }
 
     /// Get system configuration
@@ -315,35 +507,40 @@ pub mod dao_reputation_scoreboard {
     }
 
     /// Bulk initialize users
-    pub fn bulk_initialize_users(
-        ctx: Context<BulkInitializeUsers>,
+    pub fn bulk_initialize_users<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BulkInitializeUsers<'info>>,
         users: Vec<Pubkey>,
-    ) -> Result<()> {
-        instructions::bulk_initialize_users(ctx, users)
+        start_index: u32,
+    ) -> Result<BulkOperationResult> {
+        instructions::bulk_initialize_users(ctx, users, start_index)
     }
 
     /// Bulk apply decay
-    pub fn bulk_apply_decay(
-        ctx: Context<BulkApplyDecay>,
+    pub fn bulk_apply_decay<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BulkApplyDecay<'info>>,
         users: Vec<Pubkey>,
-    ) -> Result<()> {
-        instructions::bulk_apply_decay(ctx, users)
+        start_index: u32,
+    ) -> Result<BulkDecaySummary> {
+        instructions::bulk_apply_decay(ctx, users, start_index)
     }
 
     /// Bulk award achievements
-    pub fn bulk_award_achievements(
-        ctx: Context<BulkAwardAchievements>,
+    pub fn bulk_award_achievements<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BulkAwardAchievements<'info>>,
         awards: Vec<AchievementAward>,
-    ) -> Result<()> {
-        instructions::bulk_award_achievements(ctx, awards)
+        start_index: u32,
+    ) -> Result<BulkOperationResult> {
+        instructions::bulk_award_achievements(ctx, awards, start_index)
     }
 
     /// Bulk reset seasonal data
-    pub fn bulk_reset_seasonal_data(
-        ctx: Context<BulkResetSeasonalData>,
+    pub fn bulk_reset_seasonal_data<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BulkResetSeasonalData<'info>>,
+        season_id: u32,
         users: Vec<Pubkey>,
-    ) -> Result<()> {
-        instructions::bulk_reset_seasonal_data(ctx, users)
+        start_index: u32,
+    ) -> Result<BulkOperationResult> {
+        instructions::bulk_reset_seasonal_data(ctx, season_id, users, start_index)
     }
 
     /// Export leaderboard
@@ -355,11 +552,23 @@ pub mod dao_reputation_scoreboard {
         instructions::export_leaderboard(ctx, category, season_id)
     }
 
-    /// Import reputation data
+    /// Commit a Merkle root of legacy reputation records (admin only)
     pub fn import_reputation_data(
         ctx: Context<ImportReputationData>,
-        import_data: Vec<BulkReputationUpdate>,
+        migration_id: u32,
+        merkle_root: [u8; 32],
+        record_count: u32,
+    ) -> Result<()> {
+        instructions::import_reputation_data(ctx, migration_id, merkle_root, record_count)
+    }
+
+    /// Claim one imported reputation record with its Merkle proof
+    pub fn claim_imported_reputation(
+        ctx: Context<ClaimImportedReputation>,
+        migration_id: u32,
+        record: BulkReputationUpdate,
+        proof: Vec<[u8; 32]>,
     ) -> Result<()> {
-        instructions::import_reputation_data(ctx, import_data)
+        instructions::claim_imported_reputation(ctx, migration_id, record, proof)
     }
 }
\ No newline at end of file
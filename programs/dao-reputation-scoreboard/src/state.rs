@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::errors::*;
+use crate::utils::ReputationUtils;
 
 /// Global reputation system configuration - ZERO RESERVED
 #[account]
@@ -9,17 +11,17 @@ pub struct ReputationConfig {
     pub voting_cooldown: u64,
     /// Minimum account age to participate (in seconds)
     pub min_account_age: u64,
-    /// Maximum votes per user per day
+    /// Maximum votes per user per day, across all targets
     pub daily_vote_limit: u8,
     /// Minimum reputation needed to vote on others
     pub min_reputation_to_vote: u64,
     /// Category weights for scoring [governance, development, community, treasury]
     pub category_weights: [u16; 4],
-    /// Role unlock thresholds [member, contributor, senior] - ULTRA OPTIMIZED
-    pub role_thresholds: [u64; 5], // Reduced from 5 to 3
+    /// Role unlock thresholds, one per role level 1-5
+    pub role_thresholds: [u64; 5],
     /// Current active season ID
     pub current_season: u32,
-    /// Current season start timestamp
+    /// Current season start timestamp, 0 until `start_new_season` first runs
     pub season_start: i64,
     /// Season duration in seconds
     pub season_duration: u64,
@@ -33,6 +35,28 @@ pub struct ReputationConfig {
     pub initialized_at: i64,
     /// Last config update timestamp
     pub last_updated: i64,
+    /// Rank index account backing exact user rankings
+    pub rank_index: Pubkey,
+    /// Number of votes kept in each voter→target history
+    pub vote_history_depth: u8,
+    /// Seconds after casting during which a vote can be retracted or amended
+    pub vote_amend_window: u64,
+    /// Maximum votes per day from one voter on the same target
+    pub pair_daily_vote_limit: u8,
+    /// How the voter's own reputation scales the points their votes apply
+    pub voting_power: VotingPowerConfig,
+    /// Commit-reveal voting mode and its windows
+    pub commit_reveal: CommitRevealConfig,
+    /// How repeated votes from one voter on the same target lose strength
+    pub diminishing_returns: DiminishingReturnsConfig,
+    /// Reciprocal-voting and clique detection thresholds
+    pub collusion: CollusionConfig,
+    /// Governance token holding required to vote or register
+    pub token_gate: TokenGateConfig,
+    /// Stake escrowed behind downvotes until their challenge window passes
+    pub downvote_stake: DownvoteStakeConfig,
+    /// Extra requirements to vote in each category, indexed like `category_weights`
+    pub category_voter_rules: [CategoryVoterRule; 4],
     // NO RESERVED FIELD - ZERO WASTE
 }
 
@@ -44,7 +68,7 @@ impl ReputationConfig {
         1 + // daily_vote_limit
         8 + // min_reputation_to_vote
         (2 * 4) + // category_weights
-        (8 * 3) + // role_thresholds (OPTIMIZED)
+        (8 * 5) + // role_thresholds
         4 + // current_season
         8 + // season_start
         8 + // season_duration
@@ -52,7 +76,422 @@ impl ReputationConfig {
         2 + // decay_rate
         1 + // decay_enabled
         8 + // initialized_at
-        8; // last_updated - NO RESERVED
+        8 + // last_updated
+        32 + // rank_index
+        1 + // vote_history_depth
+        8 + // vote_amend_window
+        1 + // pair_daily_vote_limit
+        VotingPowerConfig::LEN + // voting_power
+        CommitRevealConfig::LEN + // commit_reveal
+        DiminishingReturnsConfig::LEN + // diminishing_returns
+        CollusionConfig::LEN + // collusion
+        TokenGateConfig::LEN + // token_gate
+        DownvoteStakeConfig::LEN + // downvote_stake
+        (CategoryVoterRule::LEN * 4); // category_voter_rules - NO RESERVED
+
+    /// Whether `start_new_season` has run, so the current season has a `SeasonData` account
+    pub fn season_started(&self) -> bool {
+        self.season_start != 0
+    }
+}
+
+/// Which parts of the voter's reputation scale their voting power
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum VotingPowerMode {
+    /// Every voter has the same power
+    #[default]
+    Flat,
+    /// Power grows with the square root of the voter's total score
+    Score,
+    /// Power follows the voter's role level
+    Role,
+    /// Score and role multipliers combined
+    ScoreAndRole,
+}
+
+/// Voting power model; multipliers are in basis points (10000 = 1x)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct VotingPowerConfig {
+    pub mode: VotingPowerMode,
+    /// Total score at which the score multiplier is 1x
+    pub reference_score: u64,
+    /// Multiplier for each role level (0-5)
+    pub role_multipliers: [u16; 6],
+    /// Lowest multiplier any voter can have
+    pub min_multiplier: u16,
+    /// Highest multiplier any voter can have
+    pub max_multiplier: u16,
+}
+
+impl VotingPowerConfig {
+    pub const LEN: usize = 1 + // mode
+        8 + // reference_score
+        (2 * 6) + // role_multipliers
+        2 + // min_multiplier
+        2; // max_multiplier
+
+    /// 1x for every voter, whatever their reputation
+    pub fn flat() -> Self {
+        Self {
+            mode: VotingPowerMode::Flat,
+            reference_score: 1000,
+            role_multipliers: [10000; 6],
+            min_multiplier: 10000,
+            max_multiplier: 10000,
+        }
+    }
+
+    /// Multiplier applied to the votes of `voter`, within the configured caps
+    pub fn multiplier(&self, voter: &UserReputation) -> u64 {
+        // sqrt(score / reference) keeps the largest holders from dominating
+        let score_multiplier = || {
+            ((voter.total_score as f64 / self.reference_score as f64).sqrt() * 10000.0) as u64
+        };
+        let role_multiplier = || self.role_multipliers[voter.role_level.min(5) as usize] as u64;
+
+        let multiplier = match self.mode {
+            VotingPowerMode::Flat => 10000,
+            VotingPowerMode::Score => score_multiplier(),
+            VotingPowerMode::Role => role_multiplier(),
+            VotingPowerMode::ScoreAndRole => score_multiplier() * role_multiplier() / 10000,
+        };
+
+        multiplier.clamp(self.min_multiplier as u64, self.max_multiplier as u64)
+    }
+}
+
+/// Commit-reveal voting: votes are committed as a hash and revealed later,
+/// so no one sees how others voted until the reveal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct CommitRevealConfig {
+    /// Whether votes must go through `commit_vote`/`reveal_vote` instead of `cast_vote`
+    pub enabled: bool,
+    /// Seconds a commitment stays sealed before it can be revealed
+    pub commit_window: u64,
+    /// Seconds after the commit window during which the vote can be revealed
+    pub reveal_window: u64,
+    /// Governance points deducted from a voter who lets a commitment expire unrevealed
+    pub unrevealed_penalty: u64,
+}
+
+impl CommitRevealConfig {
+    pub const LEN: usize = 1 + // enabled
+        8 + // commit_window
+        8 + // reveal_window
+        8; // unrevealed_penalty
+
+    /// Disabled, with windows ready for when the admin turns it on
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            commit_window: 3600,   // 1 hour sealed
+            reveal_window: 86400,  // 24 hours to reveal
+            unrevealed_penalty: 50,
+        }
+    }
+
+    /// Whether a commitment made at `committed_at` can be revealed now
+    pub fn is_reveal_open(&self, committed_at: i64, current_time: i64) -> bool {
+        current_time >= committed_at + self.commit_window as i64
+    }
+
+    /// Whether a commitment made at `committed_at` can no longer be revealed
+    pub fn is_reveal_expired(&self, committed_at: i64, current_time: i64) -> bool {
+        current_time > committed_at + self.commit_window as i64 + self.reveal_window as i64
+    }
+}
+
+/// Diminishing returns for repeated votes from one voter on the same target,
+/// counted per category and per season; multipliers are in basis points (10000 = 1x)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct DiminishingReturnsConfig {
+    /// Votes on the same target and category that count at full strength each season
+    pub full_strength_votes: u8,
+    /// Multiplier applied once more for every vote beyond those (10000 = no decay)
+    pub decay_rate: u16,
+    /// Lowest multiplier a repeated vote can fall to
+    pub min_multiplier: u16,
+}
+
+impl DiminishingReturnsConfig {
+    pub const LEN: usize = 1 + // full_strength_votes
+        2 + // decay_rate
+        2; // min_multiplier
+
+    /// Every repeated vote counts at full strength
+    pub fn disabled() -> Self {
+        Self {
+            full_strength_votes: 0,
+            decay_rate: 10000,
+            min_multiplier: 10000,
+        }
+    }
+
+    /// Multiplier for a vote preceded by `prior_votes` votes on the same target and category
+    pub fn multiplier(&self, prior_votes: u16) -> u64 {
+        let decayed_votes = prior_votes.saturating_sub(self.full_strength_votes as u16);
+        let multiplier = 10000.0 * (self.decay_rate as f64 / 10000.0).powi(decayed_votes as i32);
+        (multiplier as u64).clamp(self.min_multiplier as u64, 10000)
+    }
+}
+
+/// What happens to an upvote that looks collusive
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum CollusionAction {
+    /// Detection is disabled
+    Off,
+    /// The pair is flagged for moderators, the vote counts in full
+    #[default]
+    Flag,
+    /// The pair is flagged and the vote's points are dampened
+    Dampen,
+}
+
+/// Why a pair of users was flagged
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CollusionReason {
+    /// The two users upvote each other at a similar rate
+    Reciprocal,
+    /// The voter's upvotes mostly go to users who upvote them back
+    Clique,
+}
+
+/// Reciprocal-voting and clique detection; ratios and multipliers are in basis points
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct CollusionConfig {
+    pub action: CollusionAction,
+    /// Upvotes each way (or given, for cliques) before a pattern is judged
+    pub min_mutual_votes: u16,
+    /// Smaller-to-larger ratio of a pair's upvote flows that counts as reciprocal
+    pub reciprocity_threshold: u16,
+    /// Share of a voter's upvotes going to users who upvote them back that counts as a clique
+    pub clique_density_threshold: u16,
+    /// Multiplier applied to flagged upvotes when the action is `Dampen`
+    pub dampening_multiplier: u16,
+}
+
+impl CollusionConfig {
+    pub const LEN: usize = 1 + // action
+        2 + // min_mutual_votes
+        2 + // reciprocity_threshold
+        2 + // clique_density_threshold
+        2; // dampening_multiplier
+
+    /// Flag pairs trading 3+ upvotes each way at 80%+ reciprocity, or voters in a 90%+ dense clique
+    pub fn flagging() -> Self {
+        Self {
+            action: CollusionAction::Flag,
+            min_mutual_votes: 3,
+            reciprocity_threshold: 8000,
+            clique_density_threshold: 9000,
+            dampening_multiplier: 2500,
+        }
+    }
+
+    /// Why an upvote from the owner of `voter_summary` to `target` looks collusive, if it does
+    pub fn assess(&self, voter_summary: &CounterpartSummary, target: &Pubkey) -> Option<CollusionReason> {
+        if self.action == CollusionAction::Off {
+            return None;
+        }
+        let counterpart = voter_summary.counterpart(target)?;
+        if !counterpart.is_mutual() {
+            return None;
+        }
+
+        if counterpart.upvotes_given.min(counterpart.upvotes_received) >= self.min_mutual_votes as u32
+            && counterpart.reciprocity() >= self.reciprocity_threshold
+        {
+            return Some(CollusionReason::Reciprocal);
+        }
+        if voter_summary.upvotes_given >= self.min_mutual_votes as u32
+            && voter_summary.clique_density() >= self.clique_density_threshold
+        {
+            return Some(CollusionReason::Clique);
+        }
+        None
+    }
+}
+
+/// Which actions require holding the DAO's governance token
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TokenGateMode {
+    /// Anyone meeting the reputation requirements can vote
+    #[default]
+    Off,
+    /// Voting requires the minimum balance
+    Voting,
+    /// Voting and registering a reputation account both require the minimum balance
+    VotingAndRegistration,
+}
+
+/// Minimum balance of an SPL mint a wallet must hold to take part
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct TokenGateConfig {
+    pub mode: TokenGateMode,
+    /// Governance token mint (SPL Token or Token-2022)
+    pub mint: Pubkey,
+    /// Minimum balance in the mint's base units
+    pub min_balance: u64,
+}
+
+impl TokenGateConfig {
+    pub const LEN: usize = 1 + // mode
+        32 + // mint
+        8; // min_balance
+
+    /// Whether voting requires holding the token
+    pub fn gates_voting(&self) -> bool {
+        self.mode != TokenGateMode::Off
+    }
+
+    /// Whether registering a reputation account requires holding the token
+    pub fn gates_registration(&self) -> bool {
+        self.mode == TokenGateMode::VotingAndRegistration
+    }
+}
+
+/// What a downvote must put in escrow
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum DownvoteStakeMode {
+    /// Downvotes are free
+    #[default]
+    Off,
+    /// Downvotes escrow lamports
+    Lamports,
+    /// Downvotes escrow the configured SPL token
+    Token,
+}
+
+/// Who receives the stake of an overturned downvote
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum SlashRecipient {
+    /// The user who was wrongly downvoted
+    #[default]
+    Target,
+    /// The configured treasury wallet
+    Treasury,
+}
+
+/// Stake-backed downvotes: the stake is slashed if a moderator overturns the downvote,
+/// otherwise it is returned once the challenge window has passed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct DownvoteStakeConfig {
+    pub mode: DownvoteStakeMode,
+    /// Stake per downvote, in lamports or the mint's base units
+    pub amount: u64,
+    /// Stake mint in `Token` mode (SPL Token or Token-2022)
+    pub mint: Pubkey,
+    /// Seconds after the downvote during which the target can appeal it
+    pub challenge_window: u64,
+    pub slash_recipient: SlashRecipient,
+    /// Wallet receiving slashed stakes when the recipient is `Treasury`
+    pub treasury: Pubkey,
+}
+
+impl DownvoteStakeConfig {
+    pub const LEN: usize = 1 + // mode
+        8 + // amount
+        32 + // mint
+        8 + // challenge_window
+        1 + // slash_recipient
+        32; // treasury
+
+    /// Whether downvotes must be staked
+    pub fn is_required(&self) -> bool {
+        self.mode != DownvoteStakeMode::Off
+    }
+
+    /// Wallet receiving a slashed stake behind a downvote on `target`
+    pub fn slash_wallet(&self, target: Pubkey) -> Pubkey {
+        match self.slash_recipient {
+            SlashRecipient::Target => target,
+            SlashRecipient::Treasury => self.treasury,
+        }
+    }
+}
+
+/// Stake escrowed behind the last downvote of a voter→target pair
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct DownvoteStake {
+    /// How the stake is held; `Off` when no stake is held
+    pub mode: DownvoteStakeMode,
+    /// Mint of a token stake
+    pub mint: Pubkey,
+    pub amount: u64,
+    /// Wallet that paid the stake and gets it back if the downvote stands
+    pub staker: Pubkey,
+    /// When the downvote was cast, matching its vote history entry
+    pub staked_at: i64,
+    /// Whether the target appealed the downvote within the challenge window
+    pub appealed: bool,
+}
+
+impl DownvoteStake {
+    pub const LEN: usize = 1 + // mode
+        32 + // mint
+        8 + // amount
+        32 + // staker
+        8 + // staked_at
+        1; // appealed
+
+    /// Stake required by `stake_config` for a downvote `staker` casts now
+    pub fn new(stake_config: &DownvoteStakeConfig, staker: Pubkey, current_time: i64) -> Self {
+        Self {
+            mode: stake_config.mode,
+            mint: match stake_config.mode {
+                DownvoteStakeMode::Token => stake_config.mint,
+                _ => Pubkey::default(),
+            },
+            amount: stake_config.amount,
+            staker,
+            staked_at: current_time,
+            appealed: false,
+        }
+    }
+
+    /// Whether a stake is in escrow
+    pub fn is_held(&self) -> bool {
+        self.mode != DownvoteStakeMode::Off
+    }
+
+    /// Whether the downvote can still be appealed
+    pub fn is_challengeable(&self, challenge_window: u64, current_time: i64) -> bool {
+        current_time <= self.staked_at + challenge_window as i64
+    }
+}
+
+/// Program vault holding lamport stakes and owning the token stake vaults - ZERO RESERVED
+#[account]
+pub struct StakeVault {
+    /// Downvote stakes currently in escrow, lamports and tokens alike
+    pub open_stakes: u64,
+    /// PDA bump seed
+    pub bump: u8,
+    // NO RESERVED FIELD - ZERO WASTE
+}
+
+impl StakeVault {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // open_stakes
+        1; // bump - NO RESERVED
+}
+
+/// Requirements a voter must meet to vote in a category, on top of `min_reputation_to_vote`.
+/// The default rule lets every eligible voter vote.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct CategoryVoterRule {
+    /// Points the voter must hold in the category
+    pub min_category_points: u64,
+    /// Role level the voter must have reached (0-5)
+    pub min_role_level: u8,
+    /// Achievement the voter must have earned
+    pub required_achievement: Option<AchievementType>,
+}
+
+impl CategoryVoterRule {
+    pub const LEN: usize = 8 + // min_category_points
+        1 + // min_role_level
+        (1 + 1); // required_achievement
 }
 
 /// Individual user reputation data - ZERO RESERVED
@@ -74,194 +513,1436 @@ pub struct UserReputation {
     pub created_at: i64,
     /// Last activity timestamp
     pub last_activity: i64,
-    /// Last update timestamp
-    pub last_updated: i64,
-    /// Current streak (consecutive days)
+    /// Last update timestamp
+    pub last_updated: i64,
+    /// Current streak (consecutive days)
+    pub current_streak: u32,
+    /// Longest streak achieved
+    pub longest_streak: u32,
+    /// Best rank in any season
+    pub best_season_rank: u32,
+    /// Total votes cast by this user
+    pub votes_cast: u64,
+    /// Seasonal points [governance, development, community, treasury]
+    pub seasonal_points: [u64; 4],
+    /// When each achievement was awarded, indexed by `AchievementType`
+    pub achievement_awarded_at: [i64; 8],
+    /// Season each achievement was awarded in, indexed by `AchievementType`
+    pub achievement_seasons: [u32; 8],
+    /// Votes cast today across all targets
+    pub daily_votes_cast: u8,
+    /// Last daily vote budget reset timestamp
+    pub last_daily_vote_reset: i64,
+    /// Last season whose seasonal points were closed out
+    pub last_closed_season: u32,
+    /// End of the inactivity already decayed, 0 until the first decay
+    pub last_decay_at: i64,
+    /// Last activity day counted into the current streak
+    pub last_streak_day: i64,
+    /// Season the seasonal points were earned in
+    pub points_season: u32,
+    // NO RESERVED FIELD - ZERO WASTE
+}
+
+impl UserReputation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // user
+        (8 * 4) + // category_points
+        (8 * 4) + // raw_votes
+        8 + // total_score
+        1 + // role_level
+        4 + // achievements
+        8 + // created_at
+        8 + // last_activity
+        8 + // last_updated
+        4 + // current_streak
+        4 + // longest_streak
+        4 + // best_season_rank
+        8 + // votes_cast
+        (8 * 4) + // seasonal_points
+        (8 * 8) + // achievement_awarded_at
+        (4 * 8) + // achievement_seasons
+        1 + // daily_votes_cast
+        8 + // last_daily_vote_reset
+        4 + // last_closed_season
+        8 + // last_decay_at
+        8 + // last_streak_day
+        4; // points_season - NO RESERVED
+
+    /// Fresh reputation for a newly registered user
+    pub fn new(user: Pubkey, timestamp: i64) -> Self {
+        Self {
+            user,
+            category_points: [0; 4],
+            raw_votes: [0; 4],
+            total_score: 0,
+            role_level: 0,
+            achievements: 0,
+            created_at: timestamp,
+            last_activity: timestamp,
+            last_updated: timestamp,
+            current_streak: 0,
+            longest_streak: 0,
+            best_season_rank: 0,
+            votes_cast: 0,
+            seasonal_points: [0; 4],
+            achievement_awarded_at: [0; 8],
+            achievement_seasons: [0; 8],
+            daily_votes_cast: 0,
+            last_daily_vote_reset: timestamp,
+            last_closed_season: 0,
+            last_decay_at: 0,
+            last_streak_day: timestamp / 86400,
+            points_season: 0,
+        }
+    }
+
+    /// Check if the voter has used up today's vote budget
+    pub fn is_daily_vote_budget_spent(&mut self, limit: u8, current_time: i64) -> bool {
+        let current_day = current_time / 86400; // seconds to days
+        let last_reset_day = self.last_daily_vote_reset / 86400;
+
+        if current_day > last_reset_day {
+            self.daily_votes_cast = 0;
+            self.last_daily_vote_reset = current_time;
+        }
+
+        self.daily_votes_cast >= limit
+    }
+
+    /// Calculate total score with category weights
+    #[allow(clippy::needless_range_loop)]
+    pub fn calculate_total_score(&mut self, category_weights: &[u16; 4]) -> u64 {
+        let mut total = 0u64;
+        for i in 0..4 {
+            // Apply quadratic scaling: sqrt(raw_votes) * weight
+            let scaled_votes = (self.raw_votes[i] as f64).sqrt() as u64;
+            total += scaled_votes * category_weights[i] as u64;
+        }
+        
+        // Add streak bonus
+        total += self.current_streak as u64 * 10;
+        
+        self.total_score = total;
+        total
+    }
+
+    /// Start of the inactivity not decayed yet: the last activity or the last decay, whichever is later
+    pub fn decay_since(&self) -> i64 {
+        self.last_activity.max(self.last_decay_at)
+    }
+
+    /// Whole days of inactivity not decayed yet
+    pub fn days_undecayed(&self, current_time: i64) -> i64 {
+        (current_time - self.decay_since()) / 86400
+    }
+
+    /// Decay points for the inactive days not decayed yet at the configured daily rate, then rescore.
+    /// Returns the number of category points removed.
+    pub fn apply_decay(&mut self, config: &ReputationConfig, current_time: i64) -> u64 {
+        let decay_since = self.decay_since();
+        let decay_factor = ReputationUtils::calculate_decay_factor(
+            decay_since,
+            current_time,
+            config.decay_rate,
+        );
+
+        let mut total_decayed = 0u64;
+        for (points, raw_votes) in self.category_points.iter_mut().zip(self.raw_votes.iter_mut()) {
+            let original_points = *points;
+            *points = (original_points * decay_factor) / 10000;
+            total_decayed += original_points - *points;
+
+            // Update raw votes proportionally
+            *raw_votes = (*raw_votes * decay_factor) / 10000;
+        }
+
+        // Recalculate total score and role level with the decayed points
+        self.calculate_total_score(&config.category_weights);
+        self.role_level = ReputationUtils::calculate_role_level(self.total_score, &config.role_thresholds);
+        self.last_updated = current_time;
+
+        // Only whole days were decayed, so a partial day carries over to the next decay
+        let days_decayed = self.days_undecayed(current_time).max(0);
+        self.last_decay_at = decay_since + days_decayed * 86400;
+
+        total_decayed
+    }
+
+    /// Advance, keep or break the daily streak based on the last activity day.
+    /// Returns `None` when the user was already active today.
+    pub fn settle_streak(&mut self, current_time: i64, season_id: u32) -> Option<StreakSettlement> {
+        let current_day = current_time / 86400; // Convert to days
+        let last_activity_day = self.last_activity / 86400;
+
+        // Same day, no streak update needed
+        if last_activity_day == current_day {
+            return None;
+        }
+
+        let settlement = self.count_streak_day(last_activity_day, current_day, current_time, season_id);
+
+        // Update activity timestamp
+        self.last_activity = current_time;
+        self.last_updated = current_time;
+
+        Some(settlement)
+    }
+
+    /// Settle the streak from recorded activity alone, for keepers running on users' behalf.
+    /// A lapsed streak is broken; an activity day not yet counted goes through the same
+    /// day-boundary rules as `settle_streak`. The run itself is not activity.
+    /// Returns `None` when there is nothing to settle.
+    pub fn settle_recorded_streak(&mut self, current_time: i64, season_id: u32) -> Option<StreakSettlement> {
+        let current_day = current_time / 86400;
+        let last_activity_day = self.last_activity / 86400;
+
+        if last_activity_day < current_day - 1 {
+            // No activity yesterday or today, so the streak has lapsed
+            if self.current_streak == 0 {
+                return None;
+            }
+            self.current_streak = 0;
+        } else if last_activity_day > self.last_streak_day {
+            let settlement =
+                self.count_streak_day(self.last_streak_day, last_activity_day, current_time, season_id);
+            self.last_updated = current_time;
+            return Some(settlement);
+        } else {
+            return None;
+        }
+
+        self.last_updated = current_time;
+        Some(StreakSettlement::default())
+    }
+
+    /// Count an activity day into the streak: the day after `previous_day` extends it and
+    /// earns the streak bonus, a later day starts a new streak
+    fn count_streak_day(
+        &mut self,
+        previous_day: i64,
+        day: i64,
+        current_time: i64,
+        season_id: u32,
+    ) -> StreakSettlement {
+        let mut settlement = StreakSettlement::default();
+
+        if day == previous_day + 1 {
+            // Consecutive day, increment streak
+            self.current_streak += 1;
+
+            // Update longest streak if current streak is better
+            if self.current_streak > self.longest_streak {
+                self.longest_streak = self.current_streak;
+            }
+
+            // Calculate streak bonus
+            settlement.streak_bonus = ReputationUtils::calculate_streak_bonus(self.current_streak);
+        } else {
+            // Streak broken, reset to 1 (this day counts as new streak start)
+            self.current_streak = 1;
+        }
+        self.last_streak_day = day;
+
+        // Award the streak achievements the current streak qualifies for
+        if self.current_streak >= 7 && !self.has_achievement(AchievementType::WeeklyStreak) {
+            self.award_achievement(AchievementType::WeeklyStreak, current_time, season_id);
+            settlement.newly_awarded.push(AchievementType::WeeklyStreak);
+        }
+
+        if self.current_streak >= 30 && !self.has_achievement(AchievementType::MonthlyStreak) {
+            self.award_achievement(AchievementType::MonthlyStreak, current_time, season_id);
+            settlement.newly_awarded.push(AchievementType::MonthlyStreak);
+        }
+
+        // Apply streak bonus to governance category (participation bonus)
+        if settlement.streak_bonus > 0 {
+            self.category_points[0] += settlement.streak_bonus;
+            self.raw_votes[0] += settlement.streak_bonus;
+        }
+
+        settlement
+    }
+
+    /// Apply the points of a vote in `category` and return the points actually applied.
+    /// Upvotes add to raw votes, category and seasonal points; downvotes only remove category points.
+    pub fn apply_vote(&mut self, category: ReputationCategory, is_upvote: bool, points: u64) -> Result<u64> {
+        let index = category.to_index();
+
+        if is_upvote {
+            self.raw_votes[index] = ReputationUtils::safe_add_points(self.raw_votes[index], points)?;
+            self.category_points[index] = ReputationUtils::safe_add_points(self.category_points[index], points)?;
+            self.seasonal_points[index] = ReputationUtils::safe_add_points(self.seasonal_points[index], points)?;
+            Ok(points)
+        } else {
+            // Downvotes have less impact to prevent abuse
+            let downvote_points = points / 2;
+            self.category_points[index] =
+                ReputationUtils::safe_subtract_points(self.category_points[index], downvote_points)?;
+            Ok(downvote_points)
+        }
+    }
+
+    /// Deduct a penalty from governance points, then rescore.
+    /// Returns the number of points actually removed.
+    pub fn apply_penalty(&mut self, penalty: u64, config: &ReputationConfig, current_time: i64) -> u64 {
+        let index = ReputationCategory::Governance.to_index();
+        let removed = penalty.min(self.category_points[index]);
+        self.category_points[index] -= removed;
+        self.raw_votes[index] = self.raw_votes[index].saturating_sub(penalty);
+
+        self.calculate_total_score(&config.category_weights);
+        self.role_level = ReputationUtils::calculate_role_level(self.total_score, &config.role_thresholds);
+        self.last_updated = current_time;
+
+        removed
+    }
+
+    /// Undo the points a recorded vote applied.
+    /// Points the target has lost since then (decay, other downvotes) are not clawed back below zero,
+    /// and a vote from an earlier season leaves the current seasonal points alone.
+    pub fn reverse_vote(&mut self, entry: &VoteHistoryEntry, current_season: u32) {
+        let index = entry.category.to_index();
+
+        if entry.is_upvote {
+            self.raw_votes[index] = self.raw_votes[index].saturating_sub(entry.points);
+            self.category_points[index] = self.category_points[index].saturating_sub(entry.points);
+            if entry.season_id == current_season && self.points_season == current_season {
+                self.seasonal_points[index] = self.seasonal_points[index].saturating_sub(entry.points);
+            }
+        } else {
+            self.category_points[index] = self.category_points[index].saturating_add(entry.points);
+        }
+    }
+
+    /// Overwrite points, achievements and role level with an imported record.
+    /// Imported points also count as raw votes so the weighted total reflects them.
+    /// Achievements the user already held keep their award metadata, newly imported ones
+    /// are stamped with the import time and season, and dropped ones lose theirs.
+    pub fn apply_import(
+        &mut self,
+        update: &BulkReputationUpdate,
+        category_weights: &[u16; 4],
+        current_season: u32,
+        current_time: i64,
+    ) {
+        for index in 0..self.achievement_awarded_at.len() {
+            let bit = 1u32 << index;
+            if update.achievements & bit == 0 {
+                self.achievement_awarded_at[index] = 0;
+                self.achievement_seasons[index] = 0;
+            } else if self.achievements & bit == 0 {
+                self.achievement_awarded_at[index] = current_time;
+                self.achievement_seasons[index] = current_season;
+            }
+        }
+
+        self.category_points = update.category_points;
+        self.raw_votes = update.category_points;
+        self.achievements = update.achievements;
+        self.calculate_total_score(category_weights);
+        self.role_level = update.role_level;
+        self.last_updated = current_time;
+    }
+
+    /// Record the user's final rank in an ended season as their best if it beats it,
+    /// then clear seasonal points still held from that season.
+    /// Returns `false` when the season was already closed for this user.
+    pub fn close_season(&mut self, season_data: &SeasonData) -> bool {
+        if season_data.season_id <= self.last_closed_season {
+            return false;
+        }
+
+        if let Some(final_rank) = season_data.rank_of(&self.user) {
+            if self.best_season_rank == 0 || final_rank < self.best_season_rank {
+                self.best_season_rank = final_rank;
+            }
+        }
+
+        // Points already earned in a later season are kept
+        if self.points_season <= season_data.season_id {
+            self.seasonal_points = [0; 4];
+        }
+        self.last_closed_season = season_data.season_id;
+        true
+    }
+
+    /// Clear seasonal points left over from an earlier season before the user earns
+    /// points in `season_id`. Returns `true` when the user enters that season.
+    pub fn roll_seasonal_points(&mut self, season_id: u32) -> bool {
+        if self.points_season == season_id {
+            return false;
+        }
+
+        self.seasonal_points = [0; 4];
+        self.points_season = season_id;
+        true
+    }
+
+    /// Seasonal points summed across all categories
+    pub fn total_seasonal_points(&self) -> u64 {
+        self.seasonal_points.iter().fold(0u64, |total, points| total.saturating_add(*points))
+    }
+
+    /// Category in which the user earned the most seasonal points
+    pub fn top_seasonal_category(&self) -> ReputationCategory {
+        let mut top = 0;
+        for (index, points) in self.seasonal_points.iter().enumerate() {
+            if *points > self.seasonal_points[top] {
+                top = index;
+            }
+        }
+        ReputationCategory::ALL[top]
+    }
+
+    /// Check if user has specific achievement
+    pub fn has_achievement(&self, achievement: AchievementType) -> bool {
+        let bit_position = achievement as u32;
+        (self.achievements >> bit_position) & 1 == 1
+    }
+
+    /// Award achievement to user, recording when and in which season
+    pub fn award_achievement(&mut self, achievement: AchievementType, awarded_at: i64, season_id: u32) {
+        let bit_position = achievement as u32;
+        self.achievements |= 1 << bit_position;
+        self.achievement_awarded_at[achievement as usize] = awarded_at;
+        self.achievement_seasons[achievement as usize] = season_id;
+    }
+
+    /// Remove achievement from user along with its award metadata
+    pub fn revoke_achievement(&mut self, achievement: AchievementType) {
+        let bit_position = achievement as u32;
+        self.achievements &= !(1u32 << bit_position);
+        self.achievement_awarded_at[achievement as usize] = 0;
+        self.achievement_seasons[achievement as usize] = 0;
+    }
+
+    /// Credit the bonus points of an admin-awarded achievement, then rescore
+    pub fn apply_achievement_bonus(&mut self, achievement: AchievementType, config: &ReputationConfig) -> u64 {
+        let bonus_points = achievement.bonus_points();
+
+        // Add bonus points to governance category (achievements are governance-related)
+        self.category_points[0] += bonus_points;
+        self.calculate_total_score(&config.category_weights);
+
+        // Update role level if changed
+        self.role_level = ReputationUtils::calculate_role_level(self.total_score, &config.role_thresholds);
+
+        bonus_points
+    }
+}
+
+/// Reputation categories
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ReputationCategory {
+    #[default]
+    Governance = 0,
+    Development = 1,
+    Community = 2,
+    Treasury = 3,
+}
+
+impl ReputationCategory {
+    /// All categories in index order
+    pub const ALL: [ReputationCategory; 4] = [
+        ReputationCategory::Governance,
+        ReputationCategory::Development,
+        ReputationCategory::Community,
+        ReputationCategory::Treasury,
+    ];
+
+    /// Convert category to array index
+    pub fn to_index(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// Achievement types 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum AchievementType {
+    #[default]
+    FirstVote = 0,
+    WeeklyStreak = 1,
+    MonthlyStreak = 2,
+    TopContributor = 3,
+    ConsistentVoter = 4,
+    CategoryExpert = 5,
+    CommunityBuilder = 6,
+    SeasonWinner = 7,
+}
+
+impl AchievementType {
+    /// Bonus points granted when an admin awards the achievement
+    pub fn bonus_points(&self) -> u64 {
+        match self {
+            AchievementType::FirstVote => 50,
+            AchievementType::WeeklyStreak => 100,
+            AchievementType::MonthlyStreak => 500,
+            AchievementType::TopContributor => 1000,
+            AchievementType::ConsistentVoter => 200,
+            AchievementType::CategoryExpert => 300,
+            AchievementType::SeasonWinner => 2000,
+            AchievementType::CommunityBuilder => 750,
+        }
+    }
+}
+
+/// Deepest vote history a voting record can keep (bounded by the return data limit)
+pub const MAX_VOTE_HISTORY_DEPTH: u8 = 32;
+
+/// Vote history entry - ULTRA MINIMAL
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct VoteHistoryEntry {
+    pub category: ReputationCategory,
+    pub is_upvote: bool,
+    /// Weight the vote was cast with
+    pub vote_weight: u8,
+    /// Points added to (upvote) or removed from (downvote) the target
+    pub points: u64,
+    pub timestamp: i64,
+    /// Season the vote was cast in
+    pub season_id: u32,
+    /// Whether the voter has since retracted the vote
+    pub retracted: bool,
+}
+
+impl VoteHistoryEntry {
+    pub const LEN: usize = 1 + // category
+        1 + // is_upvote  
+        1 + // vote_weight
+        8 + // points
+        8 + // timestamp
+        4 + // season_id
+        1; // retracted
+
+    pub fn new(
+        category: ReputationCategory,
+        is_upvote: bool,
+        vote_weight: u8,
+        points: u64,
+        timestamp: i64,
+        season_id: u32,
+    ) -> Self {
+        Self {
+            category,
+            is_upvote,
+            vote_weight,
+            points,
+            timestamp,
+            season_id,
+            retracted: false,
+        }
+    }
+
+    /// Whether the vote can still be retracted or amended
+    pub fn is_amendable(&self, amend_window: u64, current_time: i64) -> bool {
+        !self.retracted && current_time.saturating_sub(self.timestamp) as u64 <= amend_window
+    }
+}
+
+/// Voting record between two users - ZERO RESERVED
+#[account]
+pub struct VotingRecord {
+    /// Voter's public key
+    pub voter: Pubkey,
+    /// Target user's public key
+    pub target: Pubkey,
+    /// Last vote timestamp
+    pub last_vote: i64,
+    /// Daily votes count
+    pub daily_votes: u8,
+    /// Last daily reset timestamp
+    pub last_daily_reset: i64,
+    /// Total votes cast on this target
+    pub total_votes_on_target: u32,
+    /// Capacity of the vote history, following the config's depth
+    pub history_depth: u8,
+    /// Most recent votes, stored as a circular buffer of `history_depth` entries
+    pub vote_history: Vec<VoteHistoryEntry>,
+    /// Slot the next vote is written to (circular buffer)
+    pub history_index: u8,
+    /// Hash of the sealed vote awaiting reveal, all zeros when none is pending
+    pub pending_commitment: [u8; 32],
+    /// When the pending commitment was made
+    pub committed_at: i64,
+    /// Season the per-category vote counts belong to
+    pub counted_season: u32,
+    /// Votes cast on the target this season in each category, driving diminishing returns
+    pub season_category_votes: [u16; 4],
+    /// Stake behind the last downvote, while it is in escrow
+    pub downvote_stake: DownvoteStake,
+    /// Season the pending commitment was made in
+    pub committed_season: u32,
+    // NO RESERVED FIELD - ZERO WASTE
+}
+
+impl VotingRecord {
+    /// Account size for a history of `history_depth` votes
+    pub fn space(history_depth: u8) -> usize {
+        8 + // discriminator
+        32 + // voter
+        32 + // target
+        8 + // last_vote
+        1 + // daily_votes
+        8 + // last_daily_reset
+        4 + // total_votes_on_target
+        1 + // history_depth
+        4 + (VoteHistoryEntry::LEN * history_depth as usize) + // vote_history
+        1 + // history_index
+        32 + // pending_commitment
+        8 + // committed_at
+        4 + // counted_season
+        (2 * 4) + // season_category_votes
+        DownvoteStake::LEN + // downvote_stake
+        4 // committed_season - NO RESERVED
+    }
+
+    /// Votes already cast on the target in `category` during `season_id`
+    pub fn season_votes_in(&mut self, category: ReputationCategory, season_id: u32) -> u16 {
+        self.roll_season(season_id);
+        self.season_category_votes[category.to_index()]
+    }
+
+    /// Count a vote on the target in `category` during `season_id`
+    pub fn count_season_vote(&mut self, category: ReputationCategory, season_id: u32) {
+        self.roll_season(season_id);
+        let votes = &mut self.season_category_votes[category.to_index()];
+        *votes = votes.saturating_add(1);
+    }
+
+    /// Stop counting a retracted or amended vote from `season_id`
+    pub fn uncount_season_vote(&mut self, category: ReputationCategory, season_id: u32) {
+        self.roll_season(season_id);
+        let votes = &mut self.season_category_votes[category.to_index()];
+        *votes = votes.saturating_sub(1);
+    }
+
+    /// Start counting afresh when a new season begins
+    fn roll_season(&mut self, season_id: u32) {
+        if self.counted_season != season_id {
+            self.counted_season = season_id;
+            self.season_category_votes = [0; 4];
+        }
+    }
+
+    /// Whether a committed vote is waiting to be revealed
+    pub fn has_pending_commitment(&self) -> bool {
+        self.pending_commitment != [0u8; 32]
+    }
+
+    /// Forget the pending commitment once it is revealed or penalized
+    pub fn clear_commitment(&mut self) {
+        self.pending_commitment = [0u8; 32];
+        self.committed_at = 0;
+        self.committed_season = 0;
+    }
+
+    /// Check if daily vote limit is reached
+    pub fn is_daily_limit_reached(&mut self, limit: u8, current_time: i64) -> bool {
+        self.update_daily_reset(current_time);
+        self.daily_votes >= limit
+    }
+
+    /// Update daily vote count and reset if new day
+    pub fn update_daily_reset(&mut self, current_time: i64) {
+        let current_day = current_time / 86400; // seconds to days
+        let last_reset_day = self.last_daily_reset / 86400;
+        
+        if current_day > last_reset_day {
+            self.daily_votes = 0;
+            self.last_daily_reset = current_time;
+        }
+    }
+
+    /// Add vote to history, overwriting the oldest entry once the buffer is full
+    pub fn add_vote_to_history(&mut self, entry: VoteHistoryEntry) {
+        if self.history_depth == 0 {
+            return;
+        }
+
+        let index = self.history_index as usize;
+        if index < self.vote_history.len() {
+            self.vote_history[index] = entry;
+        } else {
+            self.vote_history.push(entry);
+        }
+        self.history_index = ((index + 1) % self.history_depth as usize) as u8;
+    }
+
+    /// Most recently recorded vote
+    pub fn last_vote_entry_mut(&mut self) -> Option<&mut VoteHistoryEntry> {
+        let len = self.vote_history.len();
+        if len == 0 {
+            return None;
+        }
+        let index = (self.history_index as usize + len - 1) % len;
+        self.vote_history.get_mut(index)
+    }
+
+    /// Recorded vote cast at `timestamp`, if it is still in the history
+    pub fn vote_entry_at_mut(&mut self, timestamp: i64) -> Option<&mut VoteHistoryEntry> {
+        self.vote_history.iter_mut().find(|entry| entry.timestamp == timestamp)
+    }
+
+    /// Vote history ordered from oldest to newest
+    pub fn chronological_history(&self) -> Vec<VoteHistoryEntry> {
+        let mut history = self.vote_history.clone();
+        // Until the buffer wraps, the next slot is its end and entries are already in order
+        if self.history_index as usize != history.len() {
+            history.rotate_left(self.history_index as usize);
+        }
+        history
+    }
+
+    /// Change the history capacity, keeping the most recent votes that still fit
+    pub fn resize_history(&mut self, history_depth: u8) {
+        if history_depth == self.history_depth {
+            return;
+        }
+
+        let mut history = self.chronological_history();
+        let overflow = history.len().saturating_sub(history_depth as usize);
+        history.drain(..overflow);
+
+        self.history_index = (history.len() % history_depth.max(1) as usize) as u8;
+        self.history_depth = history_depth;
+        self.vote_history = history;
+    }
+}
+
+/// Counterparts tracked in each user's summary
+pub const MAX_COUNTERPARTS: usize = 16;
+
+/// Upvote flows between a user and one counterpart
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct Counterpart {
+    pub counterpart: Pubkey,
+    /// Upvotes from the user to the counterpart
+    pub upvotes_given: u32,
+    /// Upvotes from the counterpart to the user
+    pub upvotes_received: u32,
+}
+
+impl Counterpart {
+    pub const LEN: usize = 32 + // counterpart
+        4 + // upvotes_given
+        4; // upvotes_received
+
+    /// Whether upvotes flow both ways
+    pub fn is_mutual(&self) -> bool {
+        self.upvotes_given > 0 && self.upvotes_received > 0
+    }
+
+    /// Smaller flow over larger flow, in basis points
+    pub fn reciprocity(&self) -> u16 {
+        let larger = self.upvotes_given.max(self.upvotes_received);
+        if larger == 0 {
+            return 0;
+        }
+        (self.upvotes_given.min(self.upvotes_received) as u64 * 10000 / larger as u64) as u16
+    }
+}
+
+/// Per-user summary of upvote flows with their busiest counterparts - ZERO RESERVED
+#[account]
+pub struct CounterpartSummary {
+    /// User the summary belongs to
+    pub user: Pubkey,
+    /// Upvotes cast by the user, retracted ones included
+    pub upvotes_given: u32,
+    /// Upvotes received by the user, retracted ones included
+    pub upvotes_received: u32,
+    /// Busiest counterparts; the quietest is evicted once `MAX_COUNTERPARTS` are tracked
+    pub counterparts: Vec<Counterpart>,
+    // NO RESERVED FIELD - ZERO WASTE
+}
+
+impl CounterpartSummary {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // user
+        4 + // upvotes_given
+        4 + // upvotes_received
+        4 + (Counterpart::LEN * MAX_COUNTERPARTS); // counterparts - NO RESERVED
+
+    /// Tracked flows with `counterpart`
+    pub fn counterpart(&self, counterpart: &Pubkey) -> Option<&Counterpart> {
+        self.counterparts.iter().find(|entry| entry.counterpart == *counterpart)
+    }
+
+    /// Record an upvote from the user to `counterpart`
+    pub fn record_upvote_given(&mut self, counterpart: Pubkey) {
+        self.upvotes_given = self.upvotes_given.saturating_add(1);
+        let entry = self.counterpart_mut(counterpart);
+        entry.upvotes_given = entry.upvotes_given.saturating_add(1);
+    }
+
+    /// Record an upvote from `counterpart` to the user
+    pub fn record_upvote_received(&mut self, counterpart: Pubkey) {
+        self.upvotes_received = self.upvotes_received.saturating_add(1);
+        let entry = self.counterpart_mut(counterpart);
+        entry.upvotes_received = entry.upvotes_received.saturating_add(1);
+    }
+
+    /// Share of the user's upvotes that went to counterparts who upvote them back, in basis points
+    pub fn clique_density(&self) -> u16 {
+        if self.upvotes_given == 0 {
+            return 0;
+        }
+        let mutual_upvotes: u64 = self
+            .counterparts
+            .iter()
+            .filter(|entry| entry.is_mutual())
+            .map(|entry| entry.upvotes_given as u64)
+            .sum();
+        (mutual_upvotes * 10000 / self.upvotes_given as u64).min(10000) as u16
+    }
+
+    /// Tracked entry for `counterpart`, taking the quietest entry's place when the summary is full
+    fn counterpart_mut(&mut self, counterpart: Pubkey) -> &mut Counterpart {
+        let index = match self.counterparts.iter().position(|entry| entry.counterpart == counterpart) {
+            Some(index) => index,
+            None if self.counterparts.len() < MAX_COUNTERPARTS => {
+                self.counterparts.push(Counterpart { counterpart, ..Default::default() });
+                self.counterparts.len() - 1
+            }
+            None => {
+                let index = self
+                    .counterparts
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, entry)| entry.upvotes_given as u64 + entry.upvotes_received as u64)
+                    .map(|(index, _)| index)
+                    .unwrap_or(0);
+                self.counterparts[index] = Counterpart { counterpart, ..Default::default() };
+                index
+            }
+        };
+        &mut self.counterparts[index]
+    }
+}
+
+/// Flagged pairs kept in the collusion registry
+pub const MAX_FLAGGED_PAIRS: usize = 64;
+
+/// Largest page of flagged pairs that fits in return data
+pub const MAX_FLAGGED_PAGE_SIZE: u8 = 10;
+
+/// A voter→target pair flagged for moderator review
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct FlaggedPair {
+    pub voter: Pubkey,
+    pub target: Pubkey,
+    pub reason: CollusionReason,
+    /// Smaller of the pair's two upvote flows when last flagged
+    pub mutual_votes: u32,
+    /// Voter's clique density when last flagged, in basis points
+    pub clique_density: u16,
+    /// Times the pair has been flagged
+    pub flag_count: u32,
+    pub last_flagged_at: i64,
+}
+
+impl FlaggedPair {
+    pub const LEN: usize = 32 + // voter
+        32 + // target
+        1 + // reason
+        4 + // mutual_votes
+        2 + // clique_density
+        4 + // flag_count
+        8; // last_flagged_at
+
+    /// Whether the entry concerns the same two users, in either direction
+    pub fn is_between(&self, a: &Pubkey, b: &Pubkey) -> bool {
+        (self.voter == *a && self.target == *b) || (self.voter == *b && self.target == *a)
+    }
+}
+
+/// Pairs flagged by collusion detection, for moderators to review - ZERO RESERVED
+#[account]
+pub struct CollusionRegistry {
+    /// Flagged pairs; the least recently flagged is replaced once `MAX_FLAGGED_PAIRS` are kept
+    pub flagged_pairs: Vec<FlaggedPair>,
+    /// Total flags raised, repeats included
+    pub total_flags: u64,
+    /// PDA bump seed
+    pub bump: u8,
+    // NO RESERVED FIELD - ZERO WASTE
+}
+
+impl CollusionRegistry {
+    pub const LEN: usize = 8 + // discriminator
+        4 + (FlaggedPair::LEN * MAX_FLAGGED_PAIRS) + // flagged_pairs
+        8 + // total_flags
+        1; // bump - NO RESERVED
+
+    /// Flag the pair, refreshing its entry if the two users were flagged before
+    pub fn flag(
+        &mut self,
+        voter: Pubkey,
+        target: Pubkey,
+        reason: CollusionReason,
+        voter_summary: &CounterpartSummary,
+        current_time: i64,
+    ) {
+        self.total_flags = self.total_flags.saturating_add(1);
+        let mutual_votes = voter_summary
+            .counterpart(&target)
+            .map(|entry| entry.upvotes_given.min(entry.upvotes_received))
+            .unwrap_or(0);
+
+        let index = self.flagged_pairs.iter().position(|entry| entry.is_between(&voter, &target));
+        let flag_count = index.map(|index| self.flagged_pairs[index].flag_count).unwrap_or(0) + 1;
+        let flagged_pair = FlaggedPair {
+            voter,
+            target,
+            reason,
+            mutual_votes,
+            clique_density: voter_summary.clique_density(),
+            flag_count,
+            last_flagged_at: current_time,
+        };
+
+        match index {
+            Some(index) => self.flagged_pairs[index] = flagged_pair,
+            None if self.flagged_pairs.len() < MAX_FLAGGED_PAIRS => self.flagged_pairs.push(flagged_pair),
+            None => {
+                if let Some(stalest) = self.flagged_pairs.iter_mut().min_by_key(|entry| entry.last_flagged_at) {
+                    *stalest = flagged_pair;
+                }
+            }
+        }
+    }
+
+    /// Flagged pairs, most recently flagged first, starting at `offset`
+    pub fn page(&self, offset: usize, limit: usize) -> Vec<FlaggedPair> {
+        let mut flagged_pairs = self.flagged_pairs.clone();
+        flagged_pairs.sort_by_key(|entry| std::cmp::Reverse(entry.last_flagged_at));
+        flagged_pairs.into_iter().skip(offset).take(limit).collect()
+    }
+}
+
+/// Voting power a delegator lends to a delegate in some categories - ZERO RESERVED
+#[account]
+pub struct Delegation {
+    /// Member whose reputation backs the delegated votes
+    pub delegator: Pubkey,
+    /// Member allowed to vote on the delegator's behalf
+    pub delegate: Pubkey,
+    /// Delegated categories, indexed like `category_points`
+    pub categories: [bool; 4],
+    /// When the delegation lapses, 0 if it never does
+    pub expires_at: i64,
+    /// When the delegation was created or last changed
+    pub updated_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+    // NO RESERVED FIELD - ZERO WASTE
+}
+
+impl Delegation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // delegator
+        32 + // delegate
+        4 + // categories
+        8 + // expires_at
+        8 + // updated_at
+        1; // bump - NO RESERVED
+
+    /// Whether the delegate may vote in `category` at `current_time`
+    pub fn allows(&self, category: ReputationCategory, current_time: i64) -> bool {
+        self.categories[category.to_index()] && !self.is_expired(current_time)
+    }
+
+    /// Whether the delegation has lapsed
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        self.expires_at != 0 && current_time >= self.expires_at
+    }
+}
+
+/// Leaderboard entry - ULTRA MINIMAL
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct LeaderboardEntry {
+    pub user: Pubkey,
+    pub score: u64,
+    pub rank: u32,
+    pub category: ReputationCategory,
+}
+
+impl LeaderboardEntry {
+    pub const LEN: usize = 32 + // user
+        8 + // score
+        4 + // rank
+        1; // category
+}
+
+/// Number of users tracked by an on-chain leaderboard
+pub const LEADERBOARD_CAPACITY: usize = 100;
+
+/// Maximum entries returned per call, bounded by the 1024-byte return data limit
+pub const MAX_PAGE_SIZE: u8 = 20;
+
+/// Ranked slot stored inside a zero-copy leaderboard
+#[zero_copy]
+#[derive(Default, Debug)]
+pub struct LeaderboardSlot {
+    pub user: Pubkey,
+    pub score: u64,
+}
+
+impl LeaderboardSlot {
+    pub const LEN: usize = 32 + // user
+        8; // score
+}
+
+/// Top-N users sorted by total score or category points (descending) - ZERO COPY
+#[account(zero_copy)]
+pub struct Leaderboard {
+    /// Ranked slots, only the first `count` are populated
+    pub entries: [LeaderboardSlot; LEADERBOARD_CAPACITY],
+    /// Last update timestamp
+    pub last_updated: i64,
+    /// Number of populated slots
+    pub count: u32,
+    /// PDA bump seed
+    pub bump: u8,
+    /// What the board ranks: overall score or category index + 1
+    pub scope: u8,
+    /// Explicit padding to keep the layout Pod
+    pub padding: [u8; 2],
+}
+
+impl Leaderboard {
+    pub const LEN: usize = 8 + // discriminator
+        (LeaderboardSlot::LEN * LEADERBOARD_CAPACITY) + // entries
+        8 + // last_updated
+        4 + // count
+        1 + // bump
+        1 + // scope
+        2; // padding
+
+    /// Scope of the board ranked by weighted total score
+    pub const OVERALL_SCOPE: u8 = 0;
+
+    /// Scope of the board matching an optional category filter
+    pub fn scope_for(category: Option<ReputationCategory>) -> u8 {
+        category.map_or(Self::OVERALL_SCOPE, |category| category as u8 + 1)
+    }
+
+    /// Category ranked by this board, if any
+    pub fn category(&self) -> Option<ReputationCategory> {
+        ReputationCategory::ALL.get((self.scope as usize).checked_sub(1)?).copied()
+    }
+
+    /// Populated slots in rank order
+    pub fn ranked(&self) -> &[LeaderboardSlot] {
+        &self.entries[..self.count as usize]
+    }
+
+    /// Ranked entries starting at `offset`, at most `limit` of them
+    pub fn page(&self, offset: usize, limit: usize) -> Vec<LeaderboardEntry> {
+        let category = self.category().unwrap_or_default();
+
+        self.ranked()
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(limit)
+            .map(|(index, slot)| LeaderboardEntry {
+                user: slot.user,
+                score: slot.score,
+                rank: index as u32 + 1,
+                category,
+            })
+            .collect()
+    }
+
+    /// Move a user to the position matching their new score.
+    /// Users whose score drops to zero or below the last slot of a full board are dropped.
+    pub fn record_score(&mut self, user: Pubkey, score: u64, timestamp: i64) {
+        let count = place_ranked(
+            &mut self.entries,
+            self.count as usize,
+            LeaderboardSlot { user, score },
+            |slot| slot.user,
+            |slot| slot.score,
+        );
+
+        self.count = count as u32;
+        self.last_updated = timestamp;
+    }
+}
+
+/// Move `slot`'s user to the position matching its key within the first `count` of `slots`,
+/// sorted by key (descending). The user is dropped when the key is zero or below the
+/// last slot of a full board, and ties keep the earlier holder ahead.
+/// Returns the new number of populated slots.
+fn place_ranked<T: Copy + Default, K: PartialOrd + Default>(
+    slots: &mut [T],
+    count: usize,
+    slot: T,
+    user: impl Fn(&T) -> Pubkey,
+    key: impl Fn(&T) -> K,
+) -> usize {
+    let capacity = slots.len();
+    let mut count = count;
+
+    if let Some(position) = slots[..count].iter().position(|existing| user(existing) == user(&slot)) {
+        slots.copy_within(position + 1..count, position);
+        count -= 1;
+        slots[count] = T::default();
+    }
+
+    let value = key(&slot);
+    if value > K::default() {
+        let insert_at = slots[..count].partition_point(|existing| key(existing) >= value);
+        if insert_at < capacity {
+            let shift_end = count.min(capacity - 1);
+            slots.copy_within(insert_at..shift_end, insert_at + 1);
+            slots[insert_at] = slot;
+            count = (count + 1).min(capacity);
+        }
+    }
+
+    count
+}
+
+/// Maximum streak entries returned per call, bounded by the 1024-byte return data limit
+pub const MAX_STREAK_PAGE_SIZE: u8 = 19;
+
+/// Streak snapshot stored inside the zero-copy streak leaderboard
+#[zero_copy]
+#[derive(Default, Debug)]
+pub struct StreakSlot {
+    pub user: Pubkey,
+    pub last_activity: i64,
     pub current_streak: u32,
-    /// Longest streak achieved
     pub longest_streak: u32,
-    /// Best rank in any season
-    pub best_season_rank: u32,
-    /// Total votes cast by this user
-    pub votes_cast: u64,
-    /// Seasonal points [governance, development, community, treasury]
-    pub seasonal_points: [u64; 4],
-    // NO RESERVED FIELD - ZERO WASTE
 }
 
-impl UserReputation {
-    pub const LEN: usize = 8 + // discriminator
-        32 + // user
-        (8 * 4) + // category_points
-        (8 * 4) + // raw_votes
-        8 + // total_score
-        1 + // role_level
-        4 + // achievements
-        8 + // created_at
+impl StreakSlot {
+    pub const LEN: usize = 32 + // user
         8 + // last_activity
-        8 + // last_updated
         4 + // current_streak
-        4 + // longest_streak
-        4 + // best_season_rank
-        8 + // votes_cast
-        (8 * 4); // seasonal_points - NO RESERVED
+        4; // longest_streak
 
-    /// Calculate total score with category weights
-    pub fn calculate_total_score(&mut self, category_weights: &[u16; 4]) -> u64 {
-        let mut total = 0u64;
-        for i in 0..4 {
-            // Apply quadratic scaling: sqrt(raw_votes) * weight
-            let scaled_votes = (self.raw_votes[i] as f64).sqrt() as u64;
-            total += scaled_votes * category_weights[i] as u64;
+    /// Whether the streak is still alive on `current_day` (activity today or yesterday)
+    pub fn is_active(&self, current_day: i64) -> bool {
+        self.current_streak > 0 && self.last_activity / 86400 >= current_day - 1
+    }
+}
+
+/// Top-N users by current and by longest streak (descending) - ZERO COPY
+#[account(zero_copy)]
+pub struct StreakLeaderboard {
+    /// Slots ranked by current streak, only the first `current_count` are populated
+    pub by_current: [StreakSlot; LEADERBOARD_CAPACITY],
+    /// Slots ranked by longest streak, only the first `longest_count` are populated
+    pub by_longest: [StreakSlot; LEADERBOARD_CAPACITY],
+    /// Last update timestamp
+    pub last_updated: i64,
+    /// Number of populated current streak slots
+    pub current_count: u32,
+    /// Number of populated longest streak slots
+    pub longest_count: u32,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Explicit padding to keep the layout Pod
+    pub padding: [u8; 7],
+}
+
+impl StreakLeaderboard {
+    pub const LEN: usize = 8 + // discriminator
+        (StreakSlot::LEN * LEADERBOARD_CAPACITY) + // by_current
+        (StreakSlot::LEN * LEADERBOARD_CAPACITY) + // by_longest
+        8 + // last_updated
+        4 + // current_count
+        4 + // longest_count
+        1 + // bump
+        7; // padding
+
+    /// Ranked slots backing the requested leaderboard type
+    pub fn ranked(&self, leaderboard_type: StreakLeaderboardType) -> &[StreakSlot] {
+        match leaderboard_type {
+            StreakLeaderboardType::Current | StreakLeaderboardType::Active => {
+                &self.by_current[..self.current_count as usize]
+            }
+            StreakLeaderboardType::Longest => &self.by_longest[..self.longest_count as usize],
         }
-        
-        // Add streak bonus
-        total += self.current_streak as u64 * 10;
-        
-        self.total_score = total;
-        total
     }
 
-    /// Check if user has specific achievement
-    pub fn has_achievement(&self, achievement: AchievementType) -> bool {
-        let bit_position = achievement as u32;
-        (self.achievements >> bit_position) & 1 == 1
+    /// Ranked entries starting at `offset`, at most `limit` of them.
+    /// `Active` skips users whose streak has lapsed but not yet been reset.
+    pub fn page(
+        &self,
+        leaderboard_type: StreakLeaderboardType,
+        current_day: i64,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<StreakLeaderboardEntry> {
+        self.ranked(leaderboard_type)
+            .iter()
+            .filter(|slot| {
+                !matches!(leaderboard_type, StreakLeaderboardType::Active) || slot.is_active(current_day)
+            })
+            .enumerate()
+            .skip(offset)
+            .take(limit)
+            .map(|(index, slot)| StreakLeaderboardEntry {
+                user: slot.user,
+                streak_value: match leaderboard_type {
+                    StreakLeaderboardType::Longest => slot.longest_streak,
+                    _ => slot.current_streak,
+                },
+                rank: index as u32 + 1,
+                is_active: slot.is_active(current_day),
+                current_streak: slot.current_streak,
+                longest_streak: slot.longest_streak,
+                streak_bonus: ReputationUtils::calculate_streak_bonus(slot.current_streak) as u32,
+            })
+            .collect()
     }
 
-    /// Award achievement to user
-    pub fn award_achievement(&mut self, achievement: AchievementType) {
-        let bit_position = achievement as u32;
-        self.achievements |= 1 << bit_position;
+    /// Move a user to the positions matching their current and longest streaks
+    pub fn record_streak(&mut self, user_reputation: &UserReputation, timestamp: i64) {
+        let slot = StreakSlot {
+            user: user_reputation.user,
+            last_activity: user_reputation.last_activity,
+            current_streak: user_reputation.current_streak,
+            longest_streak: user_reputation.longest_streak,
+        };
+
+        let current_count = place_ranked(
+            &mut self.by_current,
+            self.current_count as usize,
+            slot,
+            |slot| slot.user,
+            |slot| slot.current_streak,
+        );
+        let longest_count = place_ranked(
+            &mut self.by_longest,
+            self.longest_count as usize,
+            slot,
+            |slot| slot.user,
+            |slot| slot.longest_streak,
+        );
+
+        self.current_count = current_count as u32;
+        self.longest_count = longest_count as u32;
+        self.last_updated = timestamp;
     }
 }
 
-/// Reputation categories
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
-pub enum ReputationCategory {
-    #[default]
-    Governance = 0,
-    Development = 1,
-    Community = 2,
-    Treasury = 3,
+/// Scores below this get a bucket of their own, so their ranks are exact
+pub const RANK_EXACT_SCORES: u64 = 4096;
+
+/// Mantissa bits kept for larger scores; buckets are 1/256 of a power of two wide
+pub const RANK_MANTISSA_BITS: u32 = 8;
+
+/// Number of score buckets in the rank index
+pub const RANK_INDEX_BUCKETS: usize = RANK_EXACT_SCORES as usize
+    + (64 - RANK_EXACT_SCORES.trailing_zeros() as usize) * (1 << RANK_MANTISSA_BITS);
+
+/// Order-statistic index: a histogram of every registered user's total score,
+/// stored as a Fenwick tree over score buckets - ZERO COPY
+#[account(zero_copy)]
+pub struct RankIndex {
+    /// Fenwick tree of user counts per score bucket, in ascending score order
+    pub tree: [u32; RANK_INDEX_BUCKETS],
+    /// Last update timestamp
+    pub last_updated: i64,
+    /// When the index was created; users registered since then are indexed at registration
+    pub initialized_at: i64,
+    /// Users registered before the index was created that are not indexed yet
+    pub backfill_remaining: u64,
+    /// Last user added by the backfill; users are walked in ascending key order
+    pub backfill_cursor: Pubkey,
+    /// Number of indexed users
+    pub count: u32,
+    /// Explicit padding to keep the layout Pod
+    pub padding: [u8; 4],
 }
 
-impl ReputationCategory {
-    /// Convert category to array index
-    pub fn to_index(&self) -> usize {
-        *self as usize
+impl RankIndex {
+    pub const LEN: usize = 8 + // discriminator
+        (4 * RANK_INDEX_BUCKETS) + // tree
+        8 + // last_updated
+        8 + // initialized_at
+        8 + // backfill_remaining
+        32 + // backfill_cursor
+        4 + // count
+        4; // padding
+
+    /// Whether every user registered before the index was created has been added
+    pub fn is_backfilled(&self) -> bool {
+        self.backfill_remaining == 0
     }
-}
 
-/// Achievement types 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
-pub enum AchievementType {
-    #[default]
-    FirstVote = 0,
-    WeeklyStreak = 1,
-    MonthlyStreak = 2,
-    TopContributor = 3,
-    ConsistentVoter = 4,
-    CategoryExpert = 5,
-    CommunityBuilder = 6,
-    SeasonWinner = 7,
-}
+    /// Whether a user's score is in the index: registered after it was created,
+    /// or already reached by the backfill
+    pub fn tracks(&self, user_reputation: &UserReputation) -> bool {
+        self.is_backfilled()
+            || user_reputation.created_at >= self.initialized_at
+            || user_reputation.user <= self.backfill_cursor
+    }
 
-/// Vote history entry - ULTRA MINIMAL
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
-pub struct VoteHistoryEntry {
-    pub category: ReputationCategory,
-    pub is_upvote: bool,
-    pub timestamp: i64,
-}
+    /// Bucket of a score: exact below `RANK_EXACT_SCORES`, then the leading
+    /// `RANK_MANTISSA_BITS` bits after the highest set bit
+    pub fn bucket_of(score: u64) -> usize {
+        if score < RANK_EXACT_SCORES {
+            return score as usize;
+        }
 
-impl VoteHistoryEntry {
-    pub const LEN: usize = 1 + // category
-        1 + // is_upvote  
-        8; // timestamp
+        let exponent = 63 - score.leading_zeros();
+        let mantissa = (score >> (exponent - RANK_MANTISSA_BITS)) as usize & ((1 << RANK_MANTISSA_BITS) - 1);
+        let octave = (exponent - RANK_EXACT_SCORES.trailing_zeros()) as usize;
+        RANK_EXACT_SCORES as usize + (octave << RANK_MANTISSA_BITS) + mantissa
+    }
 
-    pub fn new(category: ReputationCategory, is_upvote: bool, timestamp: i64) -> Self {
-        Self {
-            category,
-            is_upvote,
-            timestamp,
+    /// Users whose score falls in `bucket` or any lower bucket
+    fn count_up_to(&self, bucket: usize) -> u32 {
+        let mut total = 0;
+        let mut node = bucket + 1;
+        while node > 0 {
+            total += self.tree[node - 1];
+            node &= node - 1;
+        }
+        total
+    }
+
+    /// Add `delta` users to `bucket`
+    fn add(&mut self, bucket: usize, delta: i32) {
+        let mut node = bucket + 1;
+        while node <= RANK_INDEX_BUCKETS {
+            self.tree[node - 1] = self.tree[node - 1].wrapping_add_signed(delta);
+            node += node & node.wrapping_neg();
+        }
+    }
+
+    /// 1-based rank of a score; scores sharing a bucket share the best rank
+    pub fn rank_of(&self, score: u64) -> u32 {
+        self.count - self.count_up_to(Self::bucket_of(score)) + 1
+    }
+
+    /// Add a newly registered user's score
+    pub fn insert(&mut self, score: u64, timestamp: i64) -> Result<()> {
+        self.add(Self::bucket_of(score), 1);
+        self.count += 1;
+        self.last_updated = timestamp;
+        Ok(())
+    }
+
+    /// Move one user's score from `previous` to `current`
+    pub fn update(&mut self, previous: u64, current: u64, timestamp: i64) -> Result<()> {
+        let (from, to) = (Self::bucket_of(previous), Self::bucket_of(current));
+        if from == to {
+            return Ok(());
         }
+
+        // The previous score must be indexed
+        let below = from.checked_sub(1).map_or(0, |bucket| self.count_up_to(bucket));
+        require!(
+            self.count_up_to(from) > below,
+            ReputationError::LeaderboardCalculationFailed
+        );
+
+        self.add(from, -1);
+        self.add(to, 1);
+        self.last_updated = timestamp;
+        Ok(())
     }
 }
 
-/// Voting record between two users - ZERO RESERVED
-#[account]
-pub struct VotingRecord {
-    /// Voter's public key
-    pub voter: Pubkey,
-    /// Target user's public key
-    pub target: Pubkey,
-    /// Last vote timestamp
-    pub last_vote: i64,
-    /// Daily votes count
-    pub daily_votes: u8,
-    /// Last daily reset timestamp
-    pub last_daily_reset: i64,
-    /// Total votes cast on this target
-    pub total_votes_on_target: u32,
-    /// Vote history - MAXIMUM OPTIMIZED to 1 entry
-    pub vote_history: [VoteHistoryEntry; 1],
-    /// Current history index (circular buffer)
-    pub history_index: u8,
-    // NO RESERVED FIELD - ZERO WASTE
+/// Maximum user accounts the ranking crank processes per call
+pub const MAX_CRANK_BATCH_SIZE: usize = 10;
+
+/// Seconds after which an unfinished ranking sweep is abandoned and restarted
+pub const RANKING_SWEEP_TIMEOUT: i64 = 86400;
+
+/// Resumable ranking sweep state and the last committed ranking snapshot - ZERO COPY
+#[account(zero_copy)]
+pub struct RankingCrank {
+    /// Top of the overall leaderboard captured when the last sweep completed
+    pub snapshot: [LeaderboardSlot; LEADERBOARD_CAPACITY],
+    /// Last user processed by the running sweep; users are walked in ascending key order
+    pub cursor: Pubkey,
+    /// When the running sweep started
+    pub sweep_started_at: i64,
+    /// When the snapshot was committed
+    pub snapshot_taken_at: i64,
+    /// Number of completed sweeps
+    pub completed_sweeps: u64,
+    /// Users registered when the running sweep started
+    pub sweep_total: u64,
+    /// Pre-existing users processed by the running sweep
+    pub processed: u64,
+    /// Number of populated snapshot slots
+    pub snapshot_count: u32,
+    /// Category weights the running sweep scores with
+    pub sweep_weights: [u16; 4],
+    /// PDA bump seed
+    pub bump: u8,
+    /// Whether a sweep is running (0 or 1)
+    pub in_progress: u8,
+    /// Explicit padding to keep the layout Pod
+    pub padding: [u8; 2],
 }
 
-impl VotingRecord {
+impl RankingCrank {
     pub const LEN: usize = 8 + // discriminator
-        32 + // voter
-        32 + // target
-        8 + // last_vote
-        1 + // daily_votes
-        8 + // last_daily_reset
-        4 + // total_votes_on_target
-        (VoteHistoryEntry::LEN * 1) + // vote_history (MAXIMUM OPTIMIZED)
-        1; // history_index - NO RESERVED
+        (LeaderboardSlot::LEN * LEADERBOARD_CAPACITY) + // snapshot
+        32 + // cursor
+        8 + // sweep_started_at
+        8 + // snapshot_taken_at
+        8 + // completed_sweeps
+        8 + // sweep_total
+        8 + // processed
+        4 + // snapshot_count
+        (2 * 4) + // sweep_weights
+        1 + // bump
+        1 + // in_progress
+        2; // padding
 
-    /// Check if daily vote limit is reached
-    pub fn is_daily_limit_reached(&mut self, limit: u8, current_time: i64) -> bool {
-        self.update_daily_reset(current_time);
-        self.daily_votes >= limit
+    /// Begin a fresh sweep over every registered user
+    pub fn start_sweep(&mut self, total_users: u64, category_weights: [u16; 4], timestamp: i64) {
+        self.cursor = Pubkey::default();
+        self.sweep_started_at = timestamp;
+        self.sweep_total = total_users;
+        self.processed = 0;
+        self.sweep_weights = category_weights;
+        self.in_progress = 1;
     }
 
-    /// Update daily vote count and reset if new day
-    pub fn update_daily_reset(&mut self, current_time: i64) {
-        let current_day = current_time / 86400; // seconds to days
-        let last_reset_day = self.last_daily_reset / 86400;
-        
-        if current_day > last_reset_day {
-            self.daily_votes = 0;
-            self.last_daily_reset = current_time;
-        }
+    /// Whether every user registered at sweep start has been processed
+    pub fn is_sweep_complete(&self) -> bool {
+        self.processed >= self.sweep_total
     }
 
-    /// Add vote to history (MAXIMUM OPTIMIZED for 1 entry)
-    pub fn add_vote_to_history(&mut self, category: ReputationCategory, is_upvote: bool, timestamp: i64) {
-        let entry = VoteHistoryEntry::new(category, is_upvote, timestamp);
-        self.vote_history[0] = entry; // Always use index 0 for single entry
-        self.history_index = 0;
+    /// Whether the running sweep has outlived its timeout, e.g. because users were
+    /// skipped or the cursor was pushed past them, so it can never complete
+    pub fn is_sweep_stale(&self, timestamp: i64) -> bool {
+        timestamp.saturating_sub(self.sweep_started_at) > RANKING_SWEEP_TIMEOUT
     }
-}
 
-/// Leaderboard entry - ULTRA MINIMAL
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
-pub struct LeaderboardEntry {
-    pub user: Pubkey,
-    pub score: u64,
-    pub rank: u32,
-    pub category: ReputationCategory,
+    /// Freeze the current leaderboard as the committed ranking snapshot and close the sweep
+    pub fn commit_snapshot(&mut self, leaderboard: &Leaderboard, timestamp: i64) {
+        self.snapshot = leaderboard.entries;
+        self.snapshot_count = leaderboard.count;
+        self.snapshot_taken_at = timestamp;
+        self.completed_sweeps += 1;
+        self.in_progress = 0;
+    }
 }
 
-impl LeaderboardEntry {
-    pub const LEN: usize = 32 + // user
-        8 + // score
-        4 + // rank
-        1; // category
+/// Bonus and achievements produced by settling a user's daily streak
+#[derive(Default)]
+pub struct StreakSettlement {
+    pub streak_bonus: u64,
+    pub newly_awarded: Vec<AchievementType>,
 }
 
+/// Largest seasonal leaderboard a season can be created with
+pub const MAX_SEASON_LEADERBOARD_SIZE: u16 = 100;
+
 /// Season competition data - ZERO RESERVED
 #[account]
 pub struct SeasonData {
@@ -273,8 +1954,10 @@ pub struct SeasonData {
     pub start_time: i64,
     /// Season end timestamp
     pub end_time: i64,
-    /// Top performers - MAXIMUM OPTIMIZED to 1 entry
-    pub leaderboard: [LeaderboardEntry; 1],
+    /// Maximum number of ranked entries, fixed when the season starts
+    pub leaderboard_size: u16,
+    /// Top performers by seasonal points, frozen once the season ends
+    pub leaderboard: Vec<LeaderboardEntry>,
     /// Total participants this season
     pub total_participants: u32,
     /// Whether rewards have been distributed
@@ -287,16 +1970,240 @@ pub struct SeasonData {
 }
 
 impl SeasonData {
-    pub const LEN: usize = 8 + // discriminator
+    /// Account size for a season ranking up to `leaderboard_size` users
+    pub fn space(leaderboard_size: u16) -> usize {
+        8 + // discriminator
         4 + // season_id
         1 + // is_active
         8 + // start_time
         8 + // end_time
-        (LeaderboardEntry::LEN * 1) + // leaderboard (MAXIMUM OPTIMIZED)
+        2 + // leaderboard_size
+        4 + (LeaderboardEntry::LEN * leaderboard_size as usize) + // leaderboard
         4 + // total_participants
         1 + // rewards_distributed
         8 + // total_votes_cast
-        1; // most_active_category - NO RESERVED
+        1 // most_active_category - NO RESERVED
+    }
+
+    /// Whether seasonal points still count towards this season's standings
+    pub fn is_accepting_points(&self, current_time: i64) -> bool {
+        self.is_active && current_time < self.end_time
+    }
+
+    /// Final rank of a user on the season leaderboard, if they made it onto it
+    pub fn rank_of(&self, user: &Pubkey) -> Option<u32> {
+        self.leaderboard
+            .iter()
+            .find(|entry| entry.user == *user)
+            .map(|entry| entry.rank)
+    }
+
+    /// Move a user to the position matching their seasonal points.
+    /// Users falling below the last entry of a full leaderboard are dropped.
+    pub fn record_seasonal_points(
+        &mut self,
+        user: Pubkey,
+        points: u64,
+        category: ReputationCategory,
+    ) {
+        self.leaderboard.retain(|entry| entry.user != user);
+
+        if points > 0 {
+            // Ties keep the earlier holder ahead
+            let insert_at = self.leaderboard.partition_point(|entry| entry.score >= points);
+            if insert_at < self.leaderboard_size as usize {
+                self.leaderboard.insert(
+                    insert_at,
+                    LeaderboardEntry {
+                        user,
+                        score: points,
+                        rank: 0,
+                        category,
+                    },
+                );
+                self.leaderboard.truncate(self.leaderboard_size as usize);
+            }
+        }
+
+        for (index, entry) in self.leaderboard.iter_mut().enumerate() {
+            entry.rank = index as u32 + 1;
+        }
+    }
+}
+
+/// Legacy reputation migration committed as a Merkle root of `BulkReputationUpdate` records
+#[account]
+pub struct ReputationMigration {
+    /// Migration identifier
+    pub migration_id: u32,
+    /// Root of the Merkle tree of import records
+    pub merkle_root: [u8; 32],
+    /// Number of records committed under the root
+    pub record_count: u32,
+    /// Number of records claimed so far
+    pub claimed_count: u32,
+    /// Admin who committed the root
+    pub created_by: Pubkey,
+    /// Commit timestamp
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    // NO RESERVED FIELD - ZERO WASTE
+}
+
+impl ReputationMigration {
+    pub const LEN: usize = 8 + // discriminator
+        4 + // migration_id
+        32 + // merkle_root
+        4 + // record_count
+        4 + // claimed_count
+        32 + // created_by
+        8 + // created_at
+        1; // bump - NO RESERVED
+}
+
+/// Receipt proving a user's import record has been claimed from a migration
+#[account]
+pub struct ImportClaim {
+    /// Migration the record was claimed from
+    pub migration: Pubkey,
+    /// User whose record was claimed
+    pub user: Pubkey,
+    /// Claim timestamp
+    pub claimed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ImportClaim {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // migration
+        32 + // user
+        8 + // claimed_at
+        1; // bump - NO RESERVED
+}
+
+/// Bulk operation driven by a batch job
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchJobKind {
+    ReputationImport,
+    UserInitialization,
+    Decay,
+    AchievementAward,
+    SeasonalReset,
+    StreakUpdate,
+}
+
+/// Lifecycle of a batch job
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchJobStatus {
+    Active,
+    Completed,
+    Cancelled,
+}
+
+/// Parameters fixed when a batch job is created
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct BatchJobParams {
+    /// Number of entries the job processes across all of its transactions
+    pub total_entries: u32,
+    /// Season the job applies to (seasonal resets only)
+    pub season_id: u32,
+    /// Hash chain over the job's chunks in order, starting from zeros:
+    /// `h = sha256(h || borsh(chunk_entries))`
+    pub entries_hash: [u8; 32],
+}
+
+/// Progress of a large admin operation split over many bulk calls - ZERO RESERVED
+#[account]
+pub struct BatchJob {
+    /// Job identifier chosen by the admin
+    pub job_id: u64,
+    /// Bulk operation this job drives
+    pub kind: BatchJobKind,
+    /// Parameters fixed at creation
+    pub params: BatchJobParams,
+    /// Index of the next entry to process
+    pub cursor: u32,
+    /// Hash chain over the chunks processed so far
+    pub entries_chain: [u8; 32],
+    /// Entries applied successfully
+    pub processed_count: u32,
+    /// Entries skipped or rejected
+    pub failed_count: u32,
+    /// Admin who created the job
+    pub created_by: Pubkey,
+    /// Creation timestamp
+    pub created_at: i64,
+    /// Timestamp of the last processed chunk
+    pub updated_at: i64,
+    /// Current lifecycle status
+    pub status: BatchJobStatus,
+    /// PDA bump
+    pub bump: u8,
+    // NO RESERVED FIELD - ZERO WASTE
+}
+
+impl BatchJob {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // job_id
+        1 + // kind
+        4 + 4 + 32 + // params
+        4 + // cursor
+        32 + // entries_chain
+        4 + // processed_count
+        4 + // failed_count
+        32 + // created_by
+        8 + // created_at
+        8 + // updated_at
+        1 + // status
+        1; // bump - NO RESERVED
+
+    /// Move the cursor past one bulk call of `kind` and tally its outcome.
+    /// Each call must start at the cursor, and the job only completes once
+    /// the entries it processed, in order, match the hash committed at creation.
+    pub fn advance<T: AnchorSerialize>(
+        &mut self,
+        kind: BatchJobKind,
+        season_id: Option<u32>,
+        start_index: u32,
+        entries: &[T],
+        result: &BulkOperationResult,
+        current_time: i64,
+    ) -> Result<()> {
+        require!(
+            self.status == BatchJobStatus::Active,
+            ReputationError::BatchJobNotActive
+        );
+        require!(
+            self.kind == kind && season_id.unwrap_or(self.params.season_id) == self.params.season_id,
+            ReputationError::BatchJobMismatch
+        );
+        require!(start_index == self.cursor, ReputationError::BatchJobOutOfOrder);
+
+        let cursor = self
+            .cursor
+            .checked_add(entries.len() as u32)
+            .filter(|&cursor| cursor <= self.params.total_entries)
+            .ok_or(ReputationError::BatchJobOverrun)?;
+        let entries_chain = ReputationUtils::batch_entries_hash(&self.entries_chain, entries);
+        require!(
+            cursor < self.params.total_entries || entries_chain == self.params.entries_hash,
+            ReputationError::BatchJobEntriesMismatch
+        );
+
+        self.cursor = cursor;
+        self.entries_chain = entries_chain;
+        self.processed_count += result.succeeded;
+        self.failed_count += result.failed;
+        self.updated_at = current_time;
+
+        if self.cursor == self.params.total_entries {
+            self.status = BatchJobStatus::Completed;
+        }
+
+        Ok(())
+    }
 }
 
 /// Additional types for complex operations
@@ -324,6 +2231,26 @@ pub struct ReputationConfigUpdate {
     pub role_thresholds: Option<[u64; 5]>, // OPTIMIZED
     pub decay_rate: Option<u16>,
     pub decay_enabled: Option<bool>,
+    pub vote_history_depth: Option<u8>,
+    pub vote_amend_window: Option<u64>,
+    pub pair_daily_vote_limit: Option<u8>,
+    pub voting_power: Option<VotingPowerConfig>,
+    pub commit_reveal: Option<CommitRevealConfig>,
+    pub diminishing_returns: Option<DiminishingReturnsConfig>,
+    pub collusion: Option<CollusionConfig>,
+    pub token_gate: Option<TokenGateConfig>,
+    pub downvote_stake: Option<DownvoteStakeConfig>,
+    pub category_voter_rules: Option<[CategoryVoterRule; 4]>,
+}
+
+/// Voting history between a voter and a target, oldest vote first
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VoteHistoryView {
+    pub voter: Pubkey,
+    pub target: Pubkey,
+    pub total_votes_on_target: u32,
+    pub history_depth: u8,
+    pub entries: Vec<VoteHistoryEntry>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
@@ -334,6 +2261,33 @@ pub struct BulkReputationUpdate {
     pub role_level: u8,
 }
 
+/// Outcome of a bulk operation, with one success flag per requested entry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct BulkOperationResult {
+    pub succeeded: u32,
+    pub failed: u32,
+    pub results: Vec<bool>,
+}
+
+impl BulkOperationResult {
+    /// Record the outcome of the next entry
+    pub fn record(&mut self, success: bool) {
+        if success {
+            self.succeeded += 1;
+        } else {
+            self.failed += 1;
+        }
+        self.results.push(success);
+    }
+}
+
+/// Outcome of a bulk decay run
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct BulkDecaySummary {
+    pub total_points_decayed: u64,
+    pub outcome: BulkOperationResult,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
 pub struct DecayPreview {
     pub current_points: [u64; 4],
@@ -415,6 +2369,14 @@ pub struct SeasonInfo {
     pub days_remaining: u64,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct UserRanking {
+    pub user: Pubkey,
+    pub total_score: u64,
+    pub rank: u32,
+    pub total_ranked: u32,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
 pub struct ReputationConfigView {
     pub admin: Pubkey,
@@ -431,4 +2393,15 @@ pub struct ReputationConfigView {
     pub season_start: i64,
     pub season_duration: u64,
     pub paused: bool,
+    pub rank_index: Pubkey,
+    pub vote_history_depth: u8,
+    pub vote_amend_window: u64,
+    pub pair_daily_vote_limit: u8,
+    pub voting_power: VotingPowerConfig,
+    pub commit_reveal: CommitRevealConfig,
+    pub diminishing_returns: DiminishingReturnsConfig,
+    pub collusion: CollusionConfig,
+    pub token_gate: TokenGateConfig,
+    pub downvote_stake: DownvoteStakeConfig,
+    pub category_voter_rules: [CategoryVoterRule; 4],
 }
\ No newline at end of file
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::TokenAccount;
 use crate::state::*;
 use crate::errors::*;
 
@@ -87,6 +89,180 @@ impl ReputationUtils {
         hash(&data).to_bytes()
     }
 
+    /// Merkle leaf committing to one import record
+    pub fn import_leaf_hash(update: &BulkReputationUpdate) -> [u8; 32] {
+        use anchor_lang::solana_program::hash::hashv;
+
+        // Leaves and nodes are domain-separated so a node can never pass as a leaf
+        hashv(&[&[0u8], &update.try_to_vec().unwrap_or_default()]).to_bytes()
+    }
+
+    /// Extend a batch job's hash chain entry by entry, so the result does not
+    /// depend on how the entries were split into chunks
+    pub fn batch_entries_hash<T: AnchorSerialize>(chain: &[u8; 32], entries: &[T]) -> [u8; 32] {
+        use anchor_lang::solana_program::hash::hashv;
+
+        entries.iter().fold(*chain, |chain, entry| {
+            hashv(&[&chain, &entry.try_to_vec().unwrap_or_default()]).to_bytes()
+        })
+    }
+
+    /// Commitment sealing a vote until it is revealed; the salt keeps it from being guessed.
+    /// Binding the voter, target and season keeps it from being copied to another pair or season.
+    pub fn vote_commitment(
+        voter: &Pubkey,
+        target: &Pubkey,
+        season_id: u32,
+        is_upvote: bool,
+        category: ReputationCategory,
+        vote_weight: u8,
+        salt: &[u8; 32],
+    ) -> [u8; 32] {
+        use anchor_lang::solana_program::hash::hashv;
+
+        hashv(&[
+            voter.as_ref(),
+            target.as_ref(),
+            &season_id.to_le_bytes(),
+            &[is_upvote as u8, category.to_index() as u8, vote_weight],
+            salt,
+        ])
+        .to_bytes()
+    }
+
+    /// Verify a Merkle proof whose sibling pairs are hashed in sorted order
+    pub fn verify_merkle_proof(leaf: [u8; 32], proof: &[[u8; 32]], root: &[u8; 32]) -> bool {
+        use anchor_lang::solana_program::hash::hashv;
+
+        let computed = proof.iter().fold(leaf, |node, sibling| {
+            let (left, right) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+            hashv(&[&[1u8], &left, &right]).to_bytes()
+        });
+
+        computed == *root
+    }
+
+    /// Points a vote of `vote_weight` is worth, including the voter's small streak bonus,
+    /// scaled by the voter's voting power
+    pub fn calculate_vote_points(
+        vote_weight: u8,
+        voter: &UserReputation,
+        voting_power: &VotingPowerConfig,
+    ) -> u64 {
+        let base_points = vote_weight as u64 * 10; // Base points per vote weight
+        let streak_bonus = Self::calculate_streak_bonus(voter.current_streak);
+        let total_points = base_points + (streak_bonus / 10); // Small streak bonus
+        total_points * voting_power.multiplier(voter) / 10000
+    }
+
+    /// Validate a voting power model and its caps
+    pub fn validate_voting_power(voting_power: &VotingPowerConfig) -> Result<()> {
+        require!(
+            voting_power.reference_score > 0
+                && voting_power.min_multiplier > 0
+                && voting_power.min_multiplier <= voting_power.max_multiplier
+                && voting_power.max_multiplier <= 50000, // Max 5x
+            ReputationError::InvalidConfigurationValues
+        );
+        Ok(())
+    }
+
+    /// Validate commit-reveal windows and the unrevealed penalty
+    pub fn validate_commit_reveal(commit_reveal: &CommitRevealConfig) -> Result<()> {
+        require!(
+            commit_reveal.commit_window <= 604800 // Max 7 days sealed
+                && commit_reveal.reveal_window > 0
+                && commit_reveal.reveal_window <= 604800 // Max 7 days to reveal
+                && commit_reveal.unrevealed_penalty <= 10000,
+            ReputationError::InvalidConfigurationValues
+        );
+        Ok(())
+    }
+
+    /// Validate the diminishing-returns curve for repeated votes
+    pub fn validate_diminishing_returns(diminishing_returns: &DiminishingReturnsConfig) -> Result<()> {
+        require!(
+            diminishing_returns.decay_rate <= 10000 && diminishing_returns.min_multiplier <= 10000,
+            ReputationError::InvalidConfigurationValues
+        );
+        Ok(())
+    }
+
+    /// Validate collusion detection thresholds
+    pub fn validate_collusion(collusion: &CollusionConfig) -> Result<()> {
+        require!(
+            collusion.min_mutual_votes > 0
+                && collusion.reciprocity_threshold <= 10000
+                && collusion.clique_density_threshold <= 10000
+                && collusion.dampening_multiplier <= 10000,
+            ReputationError::InvalidConfigurationValues
+        );
+        Ok(())
+    }
+
+    /// Validate the token gate; an enabled gate needs a mint and a positive minimum
+    pub fn validate_token_gate(token_gate: &TokenGateConfig) -> Result<()> {
+        require!(
+            token_gate.mode == TokenGateMode::Off
+                || (token_gate.mint != Pubkey::default() && token_gate.min_balance > 0),
+            ReputationError::InvalidConfigurationValues
+        );
+        Ok(())
+    }
+
+    /// Check that `owner` holds at least the gate's minimum balance in `token_account`
+    pub fn check_token_gate(
+        token_gate: &TokenGateConfig,
+        token_account: Option<&TokenAccount>,
+        owner: &Pubkey,
+    ) -> Result<()> {
+        let token_account = token_account.ok_or(ReputationError::TokenAccountRequired)?;
+        require!(
+            token_account.owner == *owner && token_account.mint == token_gate.mint,
+            ReputationError::InvalidTokenAccount
+        );
+        require!(
+            token_account.amount >= token_gate.min_balance,
+            ReputationError::InsufficientTokenBalance
+        );
+        Ok(())
+    }
+
+    /// Validate downvote staking; an enabled mode needs a stake and a challenge window
+    pub fn validate_downvote_stake(downvote_stake: &DownvoteStakeConfig) -> Result<()> {
+        if !downvote_stake.is_required() {
+            return Ok(());
+        }
+        require!(
+            downvote_stake.amount > 0
+                && downvote_stake.challenge_window > 0
+                && downvote_stake.challenge_window <= 2592000 // Max 30 days
+                && (downvote_stake.mode != DownvoteStakeMode::Token || downvote_stake.mint != Pubkey::default())
+                && (downvote_stake.slash_recipient != SlashRecipient::Treasury
+                    || downvote_stake.treasury != Pubkey::default()),
+            ReputationError::InvalidConfigurationValues
+        );
+        Ok(())
+    }
+
+    /// Validate per-category voter rules; role levels only go up to 5
+    pub fn validate_category_voter_rules(rules: &[CategoryVoterRule; 4]) -> Result<()> {
+        require!(
+            rules.iter().all(|rule| rule.min_role_level <= 5),
+            ReputationError::InvalidConfigurationValues
+        );
+        Ok(())
+    }
+
+    /// Validate the number of votes kept per voter→target history
+    pub fn validate_vote_history_depth(vote_history_depth: u8) -> Result<()> {
+        require!(
+            vote_history_depth > 0 && vote_history_depth <= MAX_VOTE_HISTORY_DEPTH,
+            ReputationError::InvalidConfigurationValues
+        );
+        Ok(())
+    }
+
     /// Validate string length for program inputs
     pub fn validate_string_length(s: &str, max_length: usize) -> Result<()> {
         if s.len() > max_length {
@@ -117,6 +293,29 @@ impl ReputationUtils {
         Ok(())
     }
 
+    /// Check the category's voter rule: its points, role level and achievement requirements
+    pub fn check_category_eligibility(
+        voter_reputation: &UserReputation,
+        category: ReputationCategory,
+        rule: &CategoryVoterRule,
+    ) -> Result<()> {
+        require!(
+            voter_reputation.category_points[category.to_index()] >= rule.min_category_points,
+            ReputationError::InsufficientCategoryPoints
+        );
+        require!(
+            voter_reputation.role_level >= rule.min_role_level,
+            ReputationError::InsufficientRoleLevel
+        );
+        if let Some(achievement) = rule.required_achievement {
+            require!(
+                voter_reputation.has_achievement(achievement),
+                ReputationError::MissingRequiredAchievement
+            );
+        }
+        Ok(())
+    }
+
     /// Calculate quadratic voting weight to prevent whale dominance
     pub fn calculate_quadratic_weight(raw_votes: u64) -> u64 {
         if raw_votes == 0 {
@@ -143,6 +342,125 @@ impl ReputationUtils {
         high * 100 // Scale for precision
     }
 
+    /// Deserialize the user reputation PDA of `user` from a remaining account.
+    /// Returns `None` when the account is not that user's initialized reputation PDA.
+    pub fn load_user_reputation<'info>(
+        account_info: &'info AccountInfo<'info>,
+        user: &Pubkey,
+    ) -> Option<Account<'info, UserReputation>> {
+        let (expected_address, _) =
+            Pubkey::find_program_address(&[b"user_reputation", user.as_ref()], &crate::ID);
+        if account_info.key() != expected_address || !account_info.is_writable {
+            return None;
+        }
+
+        Account::try_from(account_info)
+            .ok()
+            .filter(|user_reputation: &Account<'info, UserReputation>| user_reputation.user == *user)
+    }
+
+    /// Create a program-owned PDA, paid by `payer`.
+    /// Addresses that were pre-funded are topped up, allocated and assigned instead.
+    pub fn create_pda_account<'info>(
+        payer: &AccountInfo<'info>,
+        new_account: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        space: usize,
+        signer_seeds: &[&[u8]],
+    ) -> Result<()> {
+        let rent_exempt_lamports = Rent::get()?.minimum_balance(space);
+        let current_lamports = new_account.lamports();
+
+        if current_lamports == 0 {
+            return system_program::create_account(
+                CpiContext::new_with_signer(
+                    system_program.clone(),
+                    system_program::CreateAccount {
+                        from: payer.clone(),
+                        to: new_account.clone(),
+                    },
+                    &[signer_seeds],
+                ),
+                rent_exempt_lamports,
+                space as u64,
+                &crate::ID,
+            );
+        }
+
+        let top_up = rent_exempt_lamports.saturating_sub(current_lamports);
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: payer.clone(),
+                        to: new_account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Allocate {
+                    account_to_allocate: new_account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            space as u64,
+        )?;
+
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Assign {
+                    account_to_assign: new_account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            &crate::ID,
+        )
+    }
+
+    /// Resize a program-owned account, topping up its rent from `payer`
+    /// or refunding the excess to `refund_recipient` when the account shrinks.
+    pub fn resize_account<'info>(
+        account: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        refund_recipient: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        space: usize,
+    ) -> Result<()> {
+        if account.data_len() == space {
+            return Ok(());
+        }
+
+        let rent_exempt_lamports = Rent::get()?.minimum_balance(space);
+        let current_lamports = account.lamports();
+
+        if rent_exempt_lamports > current_lamports {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: payer.clone(),
+                        to: account.clone(),
+                    },
+                ),
+                rent_exempt_lamports - current_lamports,
+            )?;
+        } else {
+            let refund = current_lamports - rent_exempt_lamports;
+            **account.try_borrow_mut_lamports()? -= refund;
+            **refund_recipient.try_borrow_mut_lamports()? += refund;
+        }
+
+        account.resize(space)?;
+        Ok(())
+    }
+
     /// Get current Unix timestamp
     pub fn get_current_timestamp() -> i64 {
         Clock::get().unwrap().unix_timestamp
@@ -173,7 +491,7 @@ impl ReputationUtils {
 
     /// Sort leaderboard entries by total score (descending)
     pub fn sort_leaderboard(entries: &mut [LeaderboardEntry]) {
-        entries.sort_by(|a, b| b.score.cmp(&a.score));
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.score));
         
         // Update ranks
         for (i, entry) in entries.iter_mut().enumerate() {
@@ -24,6 +24,7 @@ const PRESETS = {
     categoryWeights: [4000, 2000, 2000, 2000], // Governance focused
     roleThresholds: [500, 1000, 2500, 5000, 10000],
     decayRate: 5,                 // 0.05% per day
+    voteHistoryDepth: 10,         // Last 10 votes per voter→target pair
   },
   active: {
     name: "Active Community DAO",
@@ -34,6 +35,7 @@ const PRESETS = {
     categoryWeights: [2000, 2000, 4000, 2000], // Community focused
     roleThresholds: [50, 200, 500, 1000, 2500],
     decayRate: 15,                // 0.15% per day
+    voteHistoryDepth: 10,         // Last 10 votes per voter→target pair
   },
   development: {
     name: "Development DAO",
@@ -44,6 +46,7 @@ const PRESETS = {
     categoryWeights: [2000, 4000, 2000, 2000], // Development focused
     roleThresholds: [100, 500, 1000, 2500, 5000],
     decayRate: 10,                // 0.1% per day
+    voteHistoryDepth: 10,         // Last 10 votes per voter→target pair
   },
   balanced: {
     name: "Balanced DAO",
@@ -54,6 +57,7 @@ const PRESETS = {
     categoryWeights: [2500, 2500, 2500, 2500], // Equal weights
     roleThresholds: [100, 500, 1000, 2500, 5000],
     decayRate: 10,                // 0.1% per day
+    voteHistoryDepth: 10,         // Last 10 votes per voter→target pair
   },
 };
 
@@ -103,6 +107,7 @@ class SystemInitializer {
     console.log(`  Category Weights: [${this.config.categoryWeights.join(", ")}] (Governance, Development, Community, Treasury)`);
     console.log(`  Role Thresholds: [${this.config.roleThresholds.join(", ")}]`);
     console.log(`  Decay Rate: ${this.config.decayRate} basis points (${this.config.decayRate / 100}% per day)`);
+    console.log(`  Vote History Depth: ${this.config.voteHistoryDepth} votes per pair`);
 
     // Derive config PDA
     const [configPDA] = PublicKey.findProgramAddressSync(
@@ -110,6 +115,11 @@ class SystemInitializer {
       this.program.programId
     );
 
+    const [collusionRegistryPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collusion_registry")],
+      this.program.programId
+    );
+
     try {
       const tx = await this.program.methods
         .initializeReputationSystem(
@@ -118,10 +128,12 @@ class SystemInitializer {
           this.config.dailyVoteLimit,
           new anchor.BN(this.config.minReputationToVote),
           this.config.categoryWeights,
-          this.config.roleThresholds.map(t => new anchor.BN(t))
+          this.config.roleThresholds.map(t => new anchor.BN(t)),
+          this.config.voteHistoryDepth
         )
         .accounts({
           config: configPDA,
+          collusionRegistry: collusionRegistryPDA,
           admin: this.provider.wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -130,6 +142,7 @@ class SystemInitializer {
       console.log("✅ System initialized successfully!");
       console.log("📝 Transaction signature:", tx);
       console.log("📍 Config PDA:", configPDA.toString());
+      console.log("📍 Collusion registry PDA:", collusionRegistryPDA.toString());
       console.log("👑 Admin:", this.provider.wallet.publicKey.toString());
 
       // Save configuration to file for reference  
@@ -151,6 +164,125 @@ class SystemInitializer {
     }
   }
 
+  async initializeLeaderboards(): Promise<void> {
+    console.log("\n🏆 Initializing leaderboards...");
+
+    const [configPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("reputation_config")],
+      this.program.programId
+    );
+    const [leaderboardPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("leaderboard")],
+      this.program.programId
+    );
+
+    try {
+      // The rank index is too large for a PDA, so it is allocated up front and registered in the config
+      const rankIndex = Keypair.generate();
+      await this.program.methods
+        .initializeRankIndex()
+        .accounts({
+          config: configPDA,
+          rankIndex: rankIndex.publicKey,
+          admin: this.provider.wallet.publicKey,
+        })
+        .preInstructions([await this.program.account.rankIndex.createInstruction(rankIndex)])
+        .signers([rankIndex])
+        .rpc();
+
+      console.log("📍 Rank index:", rankIndex.publicKey.toString());
+
+      const tx = await this.program.methods
+        .initializeLeaderboard()
+        .accounts({
+          config: configPDA,
+          leaderboard: leaderboardPDA,
+          admin: this.provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      console.log("✅ Leaderboard initialized!");
+      console.log("📝 Transaction signature:", tx);
+      console.log("📍 Leaderboard PDA:", leaderboardPDA.toString());
+
+      const categories = ["governance", "development", "community", "treasury"];
+      for (let index = 0; index < categories.length; index++) {
+        const [categoryLeaderboardPDA] = PublicKey.findProgramAddressSync(
+          [Buffer.from("category_leaderboard"), Buffer.from([index])],
+          this.program.programId
+        );
+
+        await this.program.methods
+          .initializeCategoryLeaderboard({ [categories[index]]: {} } as any)
+          .accounts({
+            config: configPDA,
+            leaderboard: categoryLeaderboardPDA,
+            admin: this.provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        console.log(`📍 ${categories[index]} leaderboard PDA:`, categoryLeaderboardPDA.toString());
+      }
+
+      const [streakLeaderboardPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("streak_leaderboard")],
+        this.program.programId
+      );
+
+      await this.program.methods
+        .initializeStreakLeaderboard()
+        .accounts({
+          config: configPDA,
+          streakLeaderboard: streakLeaderboardPDA,
+          admin: this.provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      console.log("📍 Streak leaderboard PDA:", streakLeaderboardPDA.toString());
+
+      const [rankingCrankPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("ranking_crank")],
+        this.program.programId
+      );
+
+      await this.program.methods
+        .initializeRankingCrank()
+        .accounts({
+          config: configPDA,
+          rankingCrank: rankingCrankPDA,
+          admin: this.provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      console.log("📍 Ranking crank PDA:", rankingCrankPDA.toString());
+
+      const [stakeVaultPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault")],
+        this.program.programId
+      );
+
+      await this.program.methods
+        .initializeStakeVault()
+        .accounts({
+          config: configPDA,
+          stakeVault: stakeVaultPDA,
+          admin: this.provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      console.log("📍 Stake vault PDA:", stakeVaultPDA.toString());
+
+    } catch (error) {
+      console.error("❌ Leaderboard initialization failed:", error);
+      throw error;
+    }
+  }
+
   async startInitialSeason(seasonId: number): Promise<void> {
     console.log("\n🏁 Starting initial season...");
 
@@ -161,7 +293,7 @@ class SystemInitializer {
 
     try {
       const tx = await this.program.methods
-        .startNewSeason("Genesis Season", 90, seasonId) // 90 days
+        .startNewSeason("Genesis Season", 90, seasonId, 100) // 90 days, top 100
         .accounts({
           config: PublicKey.findProgramAddressSync(
             [Buffer.from("reputation_config")],
@@ -266,6 +398,7 @@ async function main() {
     const initializer = new SystemInitializer(connection, adminKeypair, programId, selectedConfig);
     
     await initializer.initialize();
+    await initializer.initializeLeaderboards();
     await initializer.startInitialSeason(1);
     await initializer.verifyInitialization();
 
@@ -3,6 +3,8 @@ import { Program } from "@coral-xyz/anchor";
 import { DaoReputationScoreboard } from "../target/types/dao_reputation_scoreboard";
 import { expect } from "chai";
 import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { createHash } from "crypto";
+import { createMint, getOrCreateAssociatedTokenAccount, mintTo } from "@solana/spl-token";
 
 describe("DAO Reputation Scoreboard", () => {
   // Configure the client
@@ -24,6 +26,32 @@ describe("DAO Reputation Scoreboard", () => {
   let user3ReputationPDA: PublicKey;
   let votingRecordPDA: PublicKey;
   let seasonDataPDA: PublicKey;
+  let currentSeasonDataPDA: PublicKey | null = null; // set once a season is started on-chain
+  let leaderboardPDA: PublicKey;
+  let streakLeaderboardPDA: PublicKey;
+  let rankingCrankPDA: PublicKey;
+  let collusionRegistryPDA: PublicKey;
+  let stakeVaultPDA: PublicKey;
+  let bulkUsers: Keypair[] = [];
+  let categoryLeaderboardPDAs: PublicKey[];
+  let rankIndex: Keypair;
+  let leaderboardAccounts: {
+    config: PublicKey;
+    rankIndex: PublicKey;
+    leaderboard: PublicKey;
+    governanceLeaderboard: PublicKey;
+    developmentLeaderboard: PublicKey;
+    communityLeaderboard: PublicKey;
+    treasuryLeaderboard: PublicKey;
+  };
+  // Stake accounts for votes that put nothing in escrow
+  const noDownvoteStake = {
+    stakeVault: null,
+    voterStakeAccount: null,
+    tokenVault: null,
+    stakeMint: null,
+    tokenProgram: null,
+  };
 
   // Configuration constants for testing
   const VOTING_COOLDOWN = 600; // 10 minutes
@@ -32,6 +60,13 @@ describe("DAO Reputation Scoreboard", () => {
   const MIN_REPUTATION_TO_VOTE = 100;
   const CATEGORY_WEIGHTS = [2500, 2500, 2500, 2500]; // Equal weights
   const ROLE_THRESHOLDS = [100, 500, 1000, 2500, 5000];
+  const VOTE_HISTORY_DEPTH = 4;
+
+  const counterpartSummaryFor = (user: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("counterpart_summary"), user.toBuffer()],
+      program.programId
+    )[0];
 
   before(async () => {
     // Generate test keypairs
@@ -79,6 +114,51 @@ describe("DAO Reputation Scoreboard", () => {
       [Buffer.from("season_data"), Buffer.from([1, 0, 0, 0])], // season 1
       program.programId
     );
+
+    [leaderboardPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("leaderboard")],
+      program.programId
+    );
+
+    [streakLeaderboardPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("streak_leaderboard")],
+      program.programId
+    );
+
+    [rankingCrankPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("ranking_crank")],
+      program.programId
+    );
+
+    [collusionRegistryPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collusion_registry")],
+      program.programId
+    );
+
+    [stakeVaultPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_vault")],
+      program.programId
+    );
+
+    categoryLeaderboardPDAs = [0, 1, 2, 3].map(index =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("category_leaderboard"), Buffer.from([index])],
+        program.programId
+      )[0]
+    );
+
+    // The rank index is a large zero-copy account allocated by the client
+    rankIndex = Keypair.generate();
+
+    leaderboardAccounts = {
+      config: configPDA,
+      rankIndex: rankIndex.publicKey,
+      leaderboard: leaderboardPDA,
+      governanceLeaderboard: categoryLeaderboardPDAs[0],
+      developmentLeaderboard: categoryLeaderboardPDAs[1],
+      communityLeaderboard: categoryLeaderboardPDAs[2],
+      treasuryLeaderboard: categoryLeaderboardPDAs[3],
+    };
   });
 
   describe("System Initialization", () => {
@@ -90,16 +170,22 @@ describe("DAO Reputation Scoreboard", () => {
           DAILY_VOTE_LIMIT,
           new anchor.BN(MIN_REPUTATION_TO_VOTE),
           CATEGORY_WEIGHTS,
-          ROLE_THRESHOLDS.map(t => new anchor.BN(t))
+          ROLE_THRESHOLDS.map(t => new anchor.BN(t)),
+          VOTE_HISTORY_DEPTH
         )
         .accounts({
           config: configPDA,
+          collusionRegistry: collusionRegistryPDA,
           admin: admin.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([admin])
         .rpc();
 
+      // The collusion registry is created with the config
+      const collusionRegistry = await program.account.collusionRegistry.fetch(collusionRegistryPDA);
+      expect(collusionRegistry.flaggedPairs.length).to.equal(0);
+
       // Verify configuration
       const config = await program.account.reputationConfig.fetch(configPDA);
       expect(config.admin.toString()).to.equal(admin.publicKey.toString());
@@ -111,6 +197,8 @@ describe("DAO Reputation Scoreboard", () => {
       expect(config.currentSeason).to.equal(1);
       expect(config.totalUsers.toNumber()).to.equal(0);
       expect(config.decayEnabled).to.be.true;
+      expect(config.voteHistoryDepth).to.equal(VOTE_HISTORY_DEPTH);
+      expect(config.voteAmendWindow.toNumber()).to.equal(3600);
     });
 
     it("Should fail to initialize with invalid configuration", async () => {
@@ -124,13 +212,15 @@ describe("DAO Reputation Scoreboard", () => {
             DAILY_VOTE_LIMIT,
             new anchor.BN(MIN_REPUTATION_TO_VOTE),
             invalidWeights,
-            ROLE_THRESHOLDS.map(t => new anchor.BN(t))
+            ROLE_THRESHOLDS.map(t => new anchor.BN(t)),
+            VOTE_HISTORY_DEPTH
           )
           .accounts({
             config: PublicKey.findProgramAddressSync(
               [Buffer.from("reputation_config_invalid")],
               program.programId
             )[0],
+            collusionRegistry: collusionRegistryPDA,
             admin: admin.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -144,6 +234,132 @@ describe("DAO Reputation Scoreboard", () => {
     });
   });
 
+  describe("Leaderboard Initialization", () => {
+    it("Should initialize the leaderboard account", async () => {
+      await program.methods
+        .initializeLeaderboard()
+        .accounts({
+          config: configPDA,
+          leaderboard: leaderboardPDA,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const leaderboard = await program.account.leaderboard.fetch(leaderboardPDA);
+      expect(leaderboard.count).to.equal(0);
+    });
+
+    it("Should initialize the rank index before any user registers", async () => {
+      await program.methods
+        .initializeRankIndex()
+        .accounts({
+          config: configPDA,
+          rankIndex: rankIndex.publicKey,
+          admin: admin.publicKey,
+        })
+        .preInstructions([await program.account.rankIndex.createInstruction(rankIndex)])
+        .signers([admin, rankIndex])
+        .rpc();
+
+      const config = await program.account.reputationConfig.fetch(configPDA);
+      expect(config.rankIndex.toString()).to.equal(rankIndex.publicKey.toString());
+
+      // Nobody registered before the index, so there is nothing to backfill
+      const index = await program.account.rankIndex.fetch(rankIndex.publicKey);
+      expect(index.backfillRemaining.toNumber()).to.equal(0);
+    });
+
+    it("Should prevent non-admin from backfilling the rank index", async () => {
+      try {
+        await program.methods
+          .backfillRankIndex()
+          .accounts({
+            config: configPDA,
+            rankIndex: rankIndex.publicKey,
+            admin: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+
+        expect.fail("Should have failed due to unauthorized admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAdmin");
+      }
+    });
+
+    it("Should initialize one leaderboard per category", async () => {
+      const categories = [{ governance: {} }, { development: {} }, { community: {} }, { treasury: {} }];
+
+      for (let i = 0; i < categories.length; i++) {
+        await program.methods
+          .initializeCategoryLeaderboard(categories[i])
+          .accounts({
+            config: configPDA,
+            leaderboard: categoryLeaderboardPDAs[i],
+            admin: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+        const leaderboard = await program.account.leaderboard.fetch(categoryLeaderboardPDAs[i]);
+        expect(leaderboard.scope).to.equal(i + 1);
+      }
+    });
+
+    it("Should initialize the streak leaderboard", async () => {
+      await program.methods
+        .initializeStreakLeaderboard()
+        .accounts({
+          config: configPDA,
+          streakLeaderboard: streakLeaderboardPDA,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const streakLeaderboard = await program.account.streakLeaderboard.fetch(streakLeaderboardPDA);
+      expect(streakLeaderboard.currentCount).to.equal(0);
+      expect(streakLeaderboard.longestCount).to.equal(0);
+    });
+
+    it("Should initialize the stake vault", async () => {
+      await program.methods
+        .initializeStakeVault()
+        .accounts({
+          config: configPDA,
+          stakeVault: stakeVaultPDA,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const stakeVault = await program.account.stakeVault.fetch(stakeVaultPDA);
+      expect(stakeVault.openStakes.toNumber()).to.equal(0);
+    });
+
+    it("Should initialize the ranking crank", async () => {
+      await program.methods
+        .initializeRankingCrank()
+        .accounts({
+          config: configPDA,
+          rankingCrank: rankingCrankPDA,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const rankingCrank = await program.account.rankingCrank.fetch(rankingCrankPDA);
+      expect(rankingCrank.inProgress).to.equal(0);
+      expect(rankingCrank.completedSweeps.toNumber()).to.equal(0);
+    });
+  });
+
   describe("User Reputation Initialization", () => {
     it("Should initialize user reputation accounts", async () => {
       // Initialize user1
@@ -151,7 +367,9 @@ describe("DAO Reputation Scoreboard", () => {
         .initializeUserReputation()
         .accounts({
           config: configPDA,
+          rankIndex: rankIndex.publicKey,
           userReputation: user1ReputationPDA,
+          userTokenAccount: null,
           user: user1.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -163,7 +381,9 @@ describe("DAO Reputation Scoreboard", () => {
         .initializeUserReputation()
         .accounts({
           config: configPDA,
+          rankIndex: rankIndex.publicKey,
           userReputation: user2ReputationPDA,
+          userTokenAccount: null,
           user: user2.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -175,7 +395,9 @@ describe("DAO Reputation Scoreboard", () => {
         .initializeUserReputation()
         .accounts({
           config: configPDA,
+          rankIndex: rankIndex.publicKey,
           userReputation: user3ReputationPDA,
+          userTokenAccount: null,
           user: user3.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -208,6 +430,7 @@ describe("DAO Reputation Scoreboard", () => {
         )
         .accounts({
           config: configPDA,
+          leaderboards: leaderboardAccounts,
           userReputation: user1ReputationPDA,
           admin: admin.publicKey,
           user: user1.publicKey,
@@ -223,10 +446,19 @@ describe("DAO Reputation Scoreboard", () => {
         .castVote(true, { governance: {} }, 5)
         .accounts({
           config: configPDA,
+          leaderboards: leaderboardAccounts,
+          seasonData: currentSeasonDataPDA,
           voterReputation: user1ReputationPDA,
           targetReputation: user2ReputationPDA,
           votingRecord: votingRecordPDA,
+          voterSummary: counterpartSummaryFor(user1.publicKey),
+          targetSummary: counterpartSummaryFor(user2.publicKey),
+          collusionRegistry: collusionRegistryPDA,
+          voterTokenAccount: null,
+          downvoteStake: noDownvoteStake,
+          delegation: null,
           voter: user1.publicKey,
+          authority: user1.publicKey,
           target: user2.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -252,13 +484,22 @@ describe("DAO Reputation Scoreboard", () => {
           .castVote(true, { governance: {} }, 5)
           .accounts({
             config: configPDA,
+            leaderboards: leaderboardAccounts,
+            seasonData: currentSeasonDataPDA,
             voterReputation: user1ReputationPDA,
             targetReputation: user1ReputationPDA,
             votingRecord: PublicKey.findProgramAddressSync(
               [Buffer.from("voting_record"), user1.publicKey.toBuffer(), user1.publicKey.toBuffer()],
               program.programId
             )[0],
+            voterSummary: counterpartSummaryFor(user1.publicKey),
+            targetSummary: counterpartSummaryFor(user1.publicKey),
+            collusionRegistry: collusionRegistryPDA,
+            voterTokenAccount: null,
+            downvoteStake: noDownvoteStake,
+            delegation: null,
             voter: user1.publicKey,
+            authority: user1.publicKey,
             target: user1.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -278,10 +519,19 @@ describe("DAO Reputation Scoreboard", () => {
           .castVote(true, { development: {} }, 3)
           .accounts({
             config: configPDA,
+            leaderboards: leaderboardAccounts,
+            seasonData: currentSeasonDataPDA,
             voterReputation: user1ReputationPDA,
             targetReputation: user2ReputationPDA,
             votingRecord: votingRecordPDA,
+            voterSummary: counterpartSummaryFor(user1.publicKey),
+            targetSummary: counterpartSummaryFor(user2.publicKey),
+            collusionRegistry: collusionRegistryPDA,
+            voterTokenAccount: null,
+            downvoteStake: noDownvoteStake,
+            delegation: null,
             voter: user1.publicKey,
+            authority: user1.publicKey,
             target: user2.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -306,10 +556,19 @@ describe("DAO Reputation Scoreboard", () => {
         .castVote(false, { governance: {} }, 4)
         .accounts({
           config: configPDA,
+          leaderboards: leaderboardAccounts,
+          seasonData: currentSeasonDataPDA,
           voterReputation: user1ReputationPDA,
           targetReputation: user2ReputationPDA,
           votingRecord: votingRecordPDA,
+          voterSummary: counterpartSummaryFor(user1.publicKey),
+          targetSummary: counterpartSummaryFor(user2.publicKey),
+          collusionRegistry: collusionRegistryPDA,
+          voterTokenAccount: null,
+          downvoteStake: noDownvoteStake,
+          delegation: null,
           voter: user1.publicKey,
+          authority: user1.publicKey,
           target: user2.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -322,6 +581,102 @@ describe("DAO Reputation Scoreboard", () => {
       // Downvote should reduce points but with less impact than upvote
       expect(pointsAfter).to.be.lessThan(pointsBefore);
     });
+
+    it("Should keep every vote between the pair in its history", async () => {
+      const history = await program.methods
+        .getVoteHistory(user1.publicKey, user2.publicKey)
+        .accounts({ votingRecord: votingRecordPDA })
+        .view();
+
+      expect(history.historyDepth).to.equal(VOTE_HISTORY_DEPTH);
+      expect(history.entries.length).to.equal(2);
+
+      // Oldest first: the weight-5 upvote, then the weight-4 downvote at half impact
+      const [upvote, downvote] = history.entries;
+      expect(upvote.isUpvote).to.be.true;
+      expect(upvote.voteWeight).to.equal(5);
+      expect(downvote.isUpvote).to.be.false;
+      expect(downvote.voteWeight).to.equal(4);
+      expect(downvote.points.toNumber()).to.be.greaterThan(0);
+      expect(downvote.timestamp.toNumber()).to.be.at.least(upvote.timestamp.toNumber());
+    });
+
+    it("Should amend the last vote by reversing its exact points", async () => {
+      const voteAccounts = {
+        config: configPDA,
+        voterReputation: user1ReputationPDA,
+        targetReputation: user2ReputationPDA,
+        votingRecord: votingRecordPDA,
+        voterSummary: counterpartSummaryFor(user1.publicKey),
+        targetSummary: counterpartSummaryFor(user2.publicKey),
+        collusionRegistry: collusionRegistryPDA,
+        leaderboards: leaderboardAccounts,
+        seasonData: currentSeasonDataPDA,
+        voterTokenAccount: null,
+        downvoteStake: noDownvoteStake,
+        stakeRecipient: null,
+        voter: user1.publicKey,
+        target: user2.publicKey,
+        systemProgram: SystemProgram.programId,
+      };
+      const before = await program.account.userReputation.fetch(user2ReputationPDA);
+      const downvotePoints = (await program.account.votingRecord.fetch(votingRecordPDA)).voteHistory[1].points;
+
+      // Turn the downvote into a weight-2 upvote
+      await program.methods
+        .amendVote(true, { governance: {} }, 2)
+        .accounts(voteAccounts)
+        .signers([user1])
+        .rpc();
+
+      const history = await program.methods
+        .getVoteHistory(user1.publicKey, user2.publicKey)
+        .accounts({ votingRecord: votingRecordPDA })
+        .view();
+      const amended = history.entries[history.entries.length - 1];
+      expect(amended.isUpvote).to.be.true;
+      expect(amended.voteWeight).to.equal(2);
+
+      const after = await program.account.userReputation.fetch(user2ReputationPDA);
+      expect(after.categoryPoints[0].toNumber()).to.equal(
+        before.categoryPoints[0].toNumber() + downvotePoints.toNumber() + amended.points.toNumber()
+      );
+    });
+
+    it("Should retract the last vote only once", async () => {
+      const before = await program.account.userReputation.fetch(user2ReputationPDA);
+      const history = await program.methods
+        .getVoteHistory(user1.publicKey, user2.publicKey)
+        .accounts({ votingRecord: votingRecordPDA })
+        .view();
+      const lastVote = history.entries[history.entries.length - 1];
+
+      const retractAccounts = {
+        config: configPDA,
+        targetReputation: user2ReputationPDA,
+        votingRecord: votingRecordPDA,
+        leaderboards: leaderboardAccounts,
+        seasonData: currentSeasonDataPDA,
+        downvoteStake: noDownvoteStake,
+        stakeRecipient: null,
+        voter: user1.publicKey,
+        target: user2.publicKey,
+      };
+
+      await program.methods.retractVote().accounts(retractAccounts).signers([user1]).rpc();
+
+      const after = await program.account.userReputation.fetch(user2ReputationPDA);
+      expect(after.categoryPoints[0].toNumber()).to.equal(
+        before.categoryPoints[0].toNumber() - lastVote.points.toNumber()
+      );
+
+      try {
+        await program.methods.retractVote().accounts(retractAccounts).signers([user1]).rpc();
+        expect.fail("Should have failed because the vote was already retracted");
+      } catch (error) {
+        expect(error.message).to.include("VoteAmendWindowExpired");
+      }
+    });
   });
 
   describe("Role Unlock System", () => {
@@ -335,6 +690,7 @@ describe("DAO Reputation Scoreboard", () => {
         )
         .accounts({
           config: configPDA,
+          leaderboards: leaderboardAccounts,
           userReputation: user2ReputationPDA,
           admin: admin.publicKey,
           user: user2.publicKey,
@@ -393,6 +749,7 @@ describe("DAO Reputation Scoreboard", () => {
         .awardAchievement(user3.publicKey, { topContributor: {} })
         .accounts({
           config: configPDA,
+          leaderboards: leaderboardAccounts,
           userReputation: user3ReputationPDA,
           admin: admin.publicKey,
         })
@@ -410,6 +767,7 @@ describe("DAO Reputation Scoreboard", () => {
           .awardAchievement(user3.publicKey, { topContributor: {} })
           .accounts({
             config: configPDA,
+            leaderboards: leaderboardAccounts,
             userReputation: user3ReputationPDA,
             admin: admin.publicKey,
           })
@@ -426,7 +784,7 @@ describe("DAO Reputation Scoreboard", () => {
   describe("Seasonal System", () => {
     it("Should start new season successfully", async () => {
       await program.methods
-        .startNewSeason("Test Season 2", 60, 2) // 60 days, season 2
+        .startNewSeason("Test Season 2", 60, 2, 25) // 60 days, season 2, top 25
         .accounts({
           config: configPDA,
           seasonData: PublicKey.findProgramAddressSync(
@@ -441,12 +799,73 @@ describe("DAO Reputation Scoreboard", () => {
 
       const config = await program.account.reputationConfig.fetch(configPDA);
       expect(config.currentSeason).to.equal(2);
+
+      currentSeasonDataPDA = PublicKey.findProgramAddressSync(
+        [Buffer.from("season_data"), new Uint8Array([2, 0, 0, 0])],
+        program.programId
+      )[0];
+      const seasonData = await program.account.seasonData.fetch(currentSeasonDataPDA);
+      expect(seasonData.leaderboardSize).to.equal(25);
+      expect(seasonData.leaderboard.length).to.equal(0);
+    });
+
+    it("Should require the season data once a season has started", async () => {
+      try {
+        await program.methods
+          .castVote(true, { governance: {} }, 1)
+          .accounts({
+            config: configPDA,
+            leaderboards: leaderboardAccounts,
+            seasonData: null, // skipping the season's points is not allowed
+            voterReputation: user1ReputationPDA,
+            targetReputation: user2ReputationPDA,
+            votingRecord: votingRecordPDA,
+            voterSummary: counterpartSummaryFor(user1.publicKey),
+            targetSummary: counterpartSummaryFor(user2.publicKey),
+            collusionRegistry: collusionRegistryPDA,
+            voterTokenAccount: null,
+            downvoteStake: noDownvoteStake,
+            delegation: null,
+            voter: user1.publicKey,
+            authority: user1.publicKey,
+            target: user2.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+
+        expect.fail("Should have failed without the season data");
+      } catch (error) {
+        expect(error.message).to.include("SeasonDataNotFound");
+      }
+    });
+
+    it("Should reject a season leaderboard size of zero", async () => {
+      try {
+        await program.methods
+          .startNewSeason("Empty Season", 30, 3, 0)
+          .accounts({
+            config: configPDA,
+            seasonData: PublicKey.findProgramAddressSync(
+              [Buffer.from("season_data"), new Uint8Array([3, 0, 0, 0])],
+              program.programId
+            )[0],
+            admin: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Should have failed due to invalid leaderboard size");
+      } catch (error) {
+        expect(error.message).to.include("InvalidConfigurationValues");
+      }
     });
 
     it("Should prevent non-admin from starting seasons", async () => {
       try {
         await program.methods
-          .startNewSeason("Unauthorized Season", 30, 3)
+          .startNewSeason("Unauthorized Season", 30, 3, 25)
           .accounts({
             config: configPDA,
             seasonData: PublicKey.findProgramAddressSync(
@@ -477,6 +896,16 @@ describe("DAO Reputation Scoreboard", () => {
         roleThresholds: null,
         decayRate: 20, // 0.2% per day
         decayEnabled: true,
+        voteHistoryDepth: null,
+        voteAmendWindow: null,
+        pairDailyVoteLimit: null,
+        votingPower: null,
+        commitReveal: null,
+        diminishingReturns: null,
+        collusion: null,
+        tokenGate: null,
+        downvoteStake: null,
+        categoryVoterRules: null,
       };
 
       await program.methods
@@ -506,6 +935,16 @@ describe("DAO Reputation Scoreboard", () => {
             roleThresholds: null,
             decayRate: null,
             decayEnabled: null,
+            voteHistoryDepth: null,
+            voteAmendWindow: null,
+            pairDailyVoteLimit: null,
+            votingPower: null,
+            commitReveal: null,
+            diminishingReturns: null,
+            collusion: null,
+            tokenGate: null,
+            downvoteStake: null,
+            categoryVoterRules: null,
           })
           .accounts({
             config: configPDA,
@@ -521,65 +960,1460 @@ describe("DAO Reputation Scoreboard", () => {
     });
   });
 
-  describe("Reputation Decay", () => {
-    it("Should calculate decay preview correctly", async () => {
-      // This would require mocking time passage in a real test
-      // For now, we'll just verify the instruction executes
-      const result = await program.methods
-        .calculateDecayPreview(user2.publicKey)
-        .accounts({
-          config: configPDA,
-          userReputation: user2ReputationPDA,
-        })
-        .view();
+  describe("Vote History Depth", () => {
+    it("Should reject a vote history depth of zero", async () => {
+      try {
+        await program.methods
+          .updateConfig({
+            votingCooldown: null,
+            minAccountAge: null,
+            dailyVoteLimit: null,
+            minReputationToVote: null,
+            categoryWeights: null,
+            roleThresholds: null,
+            decayRate: null,
+            decayEnabled: null,
+            voteHistoryDepth: 0,
+            voteAmendWindow: null,
+            pairDailyVoteLimit: null,
+            votingPower: null,
+            commitReveal: null,
+            diminishingReturns: null,
+            collusion: null,
+            tokenGate: null,
+            downvoteStake: null,
+            categoryVoterRules: null,
+          })
+          .accounts({
+            config: configPDA,
+            admin: admin.publicKey,
+          })
+          .signers([admin])
+          .rpc();
 
-      expect(result.user.toString()).to.equal(user2.publicKey.toString());
-      expect(result.decayEnabled).to.be.true;
+        expect.fail("Should have failed due to invalid history depth");
+      } catch (error) {
+        expect(error.message).to.include("InvalidConfigurationValues");
+      }
     });
+  });
+
+  describe("Voting Power", () => {
+    const configUpdate = (votingPower) => ({
+      votingCooldown: null,
+      minAccountAge: null,
+      dailyVoteLimit: null,
+      minReputationToVote: null,
+      categoryWeights: null,
+      roleThresholds: null,
+      decayRate: null,
+      decayEnabled: null,
+      voteHistoryDepth: null,
+      voteAmendWindow: null,
+      pairDailyVoteLimit: null,
+      votingPower,
+      commitReveal: null,
+      diminishingReturns: null,
+      collusion: null,
+      tokenGate: null,
+      downvoteStake: null,
+      categoryVoterRules: null,
+    });
+
+    it("Should start with flat voting power", async () => {
+      const config = await program.account.reputationConfig.fetch(configPDA);
+      expect(config.votingPower.mode).to.deep.equal({ flat: {} });
+      expect(config.votingPower.maxMultiplier).to.equal(10000);
+    });
+
+    it("Should reject voting power caps that are out of order", async () => {
+      try {
+        await program.methods
+          .updateConfig(configUpdate({
+            mode: { score: {} },
+            referenceScore: new anchor.BN(1000),
+            roleMultipliers: [5000, 7500, 10000, 12500, 15000, 20000],
+            minMultiplier: 20000,
+            maxMultiplier: 10000,
+          }))
+          .accounts({ config: configPDA, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Should have failed due to invalid caps");
+      } catch (error) {
+        expect(error.message).to.include("InvalidConfigurationValues");
+      }
+    });
+
+    it("Should let the admin weight votes by role level", async () => {
+      const votingPower = {
+        mode: { role: {} },
+        referenceScore: new anchor.BN(1000),
+        roleMultipliers: [5000, 7500, 10000, 12500, 15000, 20000],
+        minMultiplier: 5000,
+        maxMultiplier: 20000,
+      };
 
-    it("Should allow admin to reset decay timer", async () => {
       await program.methods
-        .resetDecayTimer(user2.publicKey)
-        .accounts({
-          config: configPDA,
-          userReputation: user2ReputationPDA,
-          admin: admin.publicKey,
-        })
+        .updateConfig(configUpdate(votingPower))
+        .accounts({ config: configPDA, admin: admin.publicKey })
         .signers([admin])
         .rpc();
 
-      // Verify last activity was updated
-      const user2Rep = await program.account.userReputation.fetch(user2ReputationPDA);
-      expect(user2Rep.lastActivity.toNumber()).to.be.greaterThan(0);
-    });
-  });
+      const config = await program.account.reputationConfig.fetch(configPDA);
+      expect(config.votingPower.mode).to.deep.equal({ role: {} });
+      expect(config.votingPower.roleMultipliers).to.deep.equal(votingPower.roleMultipliers);
 
-  describe("Streak System", () => {
-    it("Should update user streak correctly", async () => {
+      // Restore flat voting power for the remaining tests
       await program.methods
-        .updateUserStreak(user1.publicKey)
-        .accounts({
-          userReputation: user1ReputationPDA,
-        })
+        .updateConfig(configUpdate({ ...votingPower, mode: { flat: {} } }))
+        .accounts({ config: configPDA, admin: admin.publicKey })
+        .signers([admin])
         .rpc();
+    });
+  });
 
-      const user1Rep = await program.account.userReputation.fetch(user1ReputationPDA);
-      expect(user1Rep.currentStreak).to.be.greaterThan(0);
+  describe("Commit-Reveal Voting", () => {
+    const configUpdate = (commitReveal) => ({
+      votingCooldown: null,
+      minAccountAge: null,
+      dailyVoteLimit: null,
+      minReputationToVote: null,
+      categoryWeights: null,
+      roleThresholds: null,
+      decayRate: null,
+      decayEnabled: null,
+      voteHistoryDepth: null,
+      voteAmendWindow: null,
+      pairDailyVoteLimit: null,
+      votingPower: null,
+      commitReveal,
+      diminishingReturns: null,
+      collusion: null,
+      tokenGate: null,
+      downvoteStake: null,
+      categoryVoterRules: null,
     });
+    const commitReveal = {
+      enabled: true,
+      commitWindow: new anchor.BN(0), // revealable right away for the tests
+      revealWindow: new anchor.BN(3600),
+      unrevealedPenalty: new anchor.BN(50),
+    };
+    const salt = Array.from(createHash("sha256").update("commit-reveal salt").digest());
+    let commitSeason: Buffer;
+    // Commitments are bound to the voter (user1), the target (user3) and the current season
+    const commitmentFor = (isUpvote: boolean, categoryIndex: number, voteWeight: number, voteSalt: number[]) =>
+      Array.from(
+        createHash("sha256")
+          .update(Buffer.concat([
+            user1.publicKey.toBuffer(),
+            user3.publicKey.toBuffer(),
+            commitSeason,
+            Buffer.from([isUpvote ? 1 : 0, categoryIndex, voteWeight]),
+            Buffer.from(voteSalt),
+          ]))
+          .digest()
+      );
 
-    it("Should get streak information", async () => {
-      const streakInfo = await program.methods
-        .getUserStreakInfo()
-        .accounts({
-          userReputation: user1ReputationPDA,
-          user: user1.publicKey,
-        })
-        .view();
+    let commitRecordPDA: PublicKey;
+    let revealAccounts;
 
-      expect(streakInfo.user.toString()).to.equal(user1.publicKey.toString());
-      expect(streakInfo.currentStreak).to.be.greaterThan(0);
-    });
-  });
+    before(async () => {
+      const config = await program.account.reputationConfig.fetch(configPDA);
+      commitSeason = Buffer.alloc(4);
+      commitSeason.writeUInt32LE(config.currentSeason);
+
+      [commitRecordPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("voting_record"), user1.publicKey.toBuffer(), user3.publicKey.toBuffer()],
+        program.programId
+      );
+      revealAccounts = {
+        config: configPDA,
+        voterReputation: user1ReputationPDA,
+        targetReputation: user3ReputationPDA,
+        votingRecord: commitRecordPDA,
+        voterSummary: counterpartSummaryFor(user1.publicKey),
+        targetSummary: counterpartSummaryFor(user3.publicKey),
+        collusionRegistry: collusionRegistryPDA,
+        leaderboards: leaderboardAccounts,
+        seasonData: currentSeasonDataPDA,
+        downvoteStake: noDownvoteStake,
+        voter: user1.publicKey,
+        target: user3.publicKey,
+        systemProgram: SystemProgram.programId,
+      };
+    });
+
+    it("Should reject commits while commit-reveal is disabled", async () => {
+      const config = await program.account.reputationConfig.fetch(configPDA);
+      expect(config.commitReveal.enabled).to.be.false;
+
+      try {
+        await program.methods
+          .commitVote(commitmentFor(true, 0, 3, salt))
+          .accounts({
+            config: configPDA,
+            voterReputation: user1ReputationPDA,
+            targetReputation: user3ReputationPDA,
+            votingRecord: commitRecordPDA,
+            voterTokenAccount: null,
+            voter: user1.publicKey,
+            target: user3.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+
+        expect.fail("Should have failed because commit-reveal is disabled");
+      } catch (error) {
+        expect(error.message).to.include("CommitRevealDisabled");
+      }
+    });
+
+    it("Should require sealed votes once enabled", async () => {
+      await program.methods
+        .updateConfig(configUpdate(commitReveal))
+        .accounts({ config: configPDA, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+
+      try {
+        await program.methods
+          .castVote(true, { governance: {} }, 3)
+          .accounts({
+            config: configPDA,
+            leaderboards: leaderboardAccounts,
+            seasonData: currentSeasonDataPDA,
+            voterReputation: user1ReputationPDA,
+            targetReputation: user3ReputationPDA,
+            votingRecord: commitRecordPDA,
+            voterSummary: counterpartSummaryFor(user1.publicKey),
+            targetSummary: counterpartSummaryFor(user3.publicKey),
+            collusionRegistry: collusionRegistryPDA,
+            voterTokenAccount: null,
+            downvoteStake: noDownvoteStake,
+            delegation: null,
+            voter: user1.publicKey,
+            authority: user1.publicKey,
+            target: user3.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+
+        expect.fail("Should have failed because votes must be committed");
+      } catch (error) {
+        expect(error.message).to.include("CommitRevealRequired");
+      }
+    });
+
+    it("Should score a revealed vote only when it matches the commitment", async () => {
+      const before = await program.account.userReputation.fetch(user3ReputationPDA);
+
+      await program.methods
+        .commitVote(commitmentFor(true, 0, 3, salt))
+        .accounts({
+          config: configPDA,
+          voterReputation: user1ReputationPDA,
+          targetReputation: user3ReputationPDA,
+          votingRecord: commitRecordPDA,
+          voterTokenAccount: null,
+          voter: user1.publicKey,
+          target: user3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      // Sealed: the target's points are untouched until the reveal
+      const sealed = await program.account.userReputation.fetch(user3ReputationPDA);
+      expect(sealed.categoryPoints[0].toNumber()).to.equal(before.categoryPoints[0].toNumber());
+
+      // The pending commitment must be revealed before voting on the target again
+      try {
+        await program.methods
+          .castVote(true, { governance: {} }, 3)
+          .accounts({
+            config: configPDA,
+            leaderboards: leaderboardAccounts,
+            seasonData: currentSeasonDataPDA,
+            voterReputation: user1ReputationPDA,
+            targetReputation: user3ReputationPDA,
+            votingRecord: commitRecordPDA,
+            voterSummary: counterpartSummaryFor(user1.publicKey),
+            targetSummary: counterpartSummaryFor(user3.publicKey),
+            collusionRegistry: collusionRegistryPDA,
+            voterTokenAccount: null,
+            downvoteStake: noDownvoteStake,
+            delegation: null,
+            voter: user1.publicKey,
+            authority: user1.publicKey,
+            target: user3.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+
+        expect.fail("Should have failed because a commitment is pending");
+      } catch (error) {
+        expect(error.message).to.include("CommitmentPending");
+      }
+
+      try {
+        await program.methods
+          .revealVote(false, { governance: {} }, 3, salt)
+          .accounts(revealAccounts)
+          .signers([user1])
+          .rpc();
+
+        expect.fail("Should have failed because the vote does not match the commitment");
+      } catch (error) {
+        expect(error.message).to.include("CommitmentMismatch");
+      }
+
+      await program.methods
+        .revealVote(true, { governance: {} }, 3, salt)
+        .accounts(revealAccounts)
+        .signers([user1])
+        .rpc();
+
+      const after = await program.account.userReputation.fetch(user3ReputationPDA);
+      expect(after.categoryPoints[0].toNumber()).to.be.greaterThan(before.categoryPoints[0].toNumber());
+
+      const votingRecord = await program.account.votingRecord.fetch(commitRecordPDA);
+      expect(votingRecord.pendingCommitment).to.deep.equal(new Array(32).fill(0));
+      expect(votingRecord.voteHistory[0].voteWeight).to.equal(3);
+      const config = await program.account.reputationConfig.fetch(configPDA);
+      expect(votingRecord.voteHistory[0].seasonId).to.equal(config.currentSeason);
+    });
+
+    it("Should reject amending a revealed vote while commit-reveal is enabled", async () => {
+      try {
+        await program.methods
+          .amendVote(false, { governance: {} }, 3)
+          .accounts({
+            config: configPDA,
+            voterReputation: user1ReputationPDA,
+            targetReputation: user3ReputationPDA,
+            votingRecord: commitRecordPDA,
+            voterSummary: counterpartSummaryFor(user1.publicKey),
+            targetSummary: counterpartSummaryFor(user3.publicKey),
+            collusionRegistry: collusionRegistryPDA,
+            leaderboards: leaderboardAccounts,
+            seasonData: currentSeasonDataPDA,
+            voterTokenAccount: null,
+            downvoteStake: noDownvoteStake,
+            stakeRecipient: null,
+            voter: user1.publicKey,
+            target: user3.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+
+        expect.fail("Should have failed because amendments bypass the commitment");
+      } catch (error) {
+        expect(error.message).to.include("CommitRevealRequired");
+      }
+    });
+
+    it("Should only penalize commitments that expired unrevealed", async () => {
+      try {
+        await program.methods
+          .penalizeUnrevealedVote()
+          .accounts({
+            config: configPDA,
+            voterReputation: user1ReputationPDA,
+            votingRecord: commitRecordPDA,
+            leaderboards: leaderboardAccounts,
+            voter: user1.publicKey,
+            target: user3.publicKey,
+          })
+          .rpc();
+
+        expect.fail("Should have failed because nothing is pending");
+      } catch (error) {
+        expect(error.message).to.include("NoPendingCommitment");
+      } finally {
+        // Restore direct voting for the remaining tests
+        await program.methods
+          .updateConfig(configUpdate({ ...commitReveal, enabled: false }))
+          .accounts({ config: configPDA, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+      }
+    });
+  });
+
+  describe("Diminishing Returns", () => {
+    const configUpdate = (diminishingReturns) => ({
+      votingCooldown: null,
+      minAccountAge: null,
+      dailyVoteLimit: null,
+      minReputationToVote: null,
+      categoryWeights: null,
+      roleThresholds: null,
+      decayRate: null,
+      decayEnabled: null,
+      voteHistoryDepth: null,
+      voteAmendWindow: null,
+      pairDailyVoteLimit: null,
+      votingPower: null,
+      commitReveal: null,
+      diminishingReturns,
+      collusion: null,
+      tokenGate: null,
+      downvoteStake: null,
+      categoryVoterRules: null,
+    });
+
+    it("Should keep repeated votes at full strength by default", async () => {
+      const config = await program.account.reputationConfig.fetch(configPDA);
+      expect(config.diminishingReturns.decayRate).to.equal(10000);
+      expect(config.diminishingReturns.minMultiplier).to.equal(10000);
+    });
+
+    it("Should let the admin opt into a decay curve", async () => {
+      await program.methods
+        .updateConfig(configUpdate({ fullStrengthVotes: 2, decayRate: 5000, minMultiplier: 1000 }))
+        .accounts({ config: configPDA, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+
+      try {
+        const config = await program.account.reputationConfig.fetch(configPDA);
+        expect(config.diminishingReturns.fullStrengthVotes).to.equal(2);
+        expect(config.diminishingReturns.decayRate).to.equal(5000);
+        expect(config.diminishingReturns.minMultiplier).to.equal(1000);
+      } finally {
+        // Back to full-strength votes for the remaining tests
+        await program.methods
+          .updateConfig(configUpdate({ fullStrengthVotes: 0, decayRate: 10000, minMultiplier: 10000 }))
+          .accounts({ config: configPDA, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+      }
+    });
+
+    it("Should leave retracted votes out of the season's tally", async () => {
+      const votingRecord = await program.account.votingRecord.fetch(votingRecordPDA);
+      const countedGovernanceVotes = votingRecord.voteHistory.filter(
+        vote => !vote.retracted && vote.category.governance !== undefined
+      ).length;
+
+      expect(votingRecord.seasonCategoryVotes[0]).to.equal(countedGovernanceVotes);
+    });
+
+    it("Should reject a decay rate above 1x", async () => {
+      try {
+        await program.methods
+          .updateConfig(configUpdate({ fullStrengthVotes: 1, decayRate: 12000, minMultiplier: 1000 }))
+          .accounts({ config: configPDA, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Should have failed due to invalid decay rate");
+      } catch (error) {
+        expect(error.message).to.include("InvalidConfigurationValues");
+      }
+    });
+  });
+
+  describe("Collusion Detection", () => {
+    it("Should flag reciprocal votes without dampening by default", async () => {
+      const config = await program.account.reputationConfig.fetch(configPDA);
+      expect(config.collusion.action).to.deep.equal({ flag: {} });
+      expect(config.collusion.minMutualVotes).to.equal(3);
+      expect(config.collusion.reciprocityThreshold).to.equal(8000);
+    });
+
+    it("Should track upvote flows in both users' counterpart summaries", async () => {
+      const voterSummary = await program.account.counterpartSummary.fetch(counterpartSummaryFor(user1.publicKey));
+      const targetSummary = await program.account.counterpartSummary.fetch(counterpartSummaryFor(user2.publicKey));
+
+      const given = voterSummary.counterparts.find(entry => entry.counterpart.equals(user2.publicKey));
+      const received = targetSummary.counterparts.find(entry => entry.counterpart.equals(user1.publicKey));
+      expect(given.upvotesGiven).to.be.greaterThan(0);
+      expect(received.upvotesReceived).to.equal(given.upvotesGiven);
+    });
+
+    it("Should page flagged pairs for moderators", async () => {
+      const flaggedPairs = await program.methods
+        .getFlaggedPairs(0, 10)
+        .accounts({ collusionRegistry: collusionRegistryPDA })
+        .view();
+
+      const collusionRegistry = await program.account.collusionRegistry.fetch(collusionRegistryPDA);
+      expect(flaggedPairs.length).to.equal(Math.min(collusionRegistry.flaggedPairs.length, 10));
+
+      try {
+        await program.methods
+          .getFlaggedPairs(0, 11)
+          .accounts({ collusionRegistry: collusionRegistryPDA })
+          .view();
+
+        expect.fail("Should have failed due to page size");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPaginationParameters");
+      }
+    });
+
+    it("Should reject a zero mutual vote minimum", async () => {
+      try {
+        await program.methods
+          .updateConfig({
+            votingCooldown: null,
+            minAccountAge: null,
+            dailyVoteLimit: null,
+            minReputationToVote: null,
+            categoryWeights: null,
+            roleThresholds: null,
+            decayRate: null,
+            decayEnabled: null,
+            voteHistoryDepth: null,
+            voteAmendWindow: null,
+            pairDailyVoteLimit: null,
+            votingPower: null,
+            commitReveal: null,
+            diminishingReturns: null,
+            collusion: {
+              action: { dampen: {} },
+              minMutualVotes: 0,
+              reciprocityThreshold: 8000,
+              cliqueDensityThreshold: 9000,
+              dampeningMultiplier: 2500,
+            },
+            tokenGate: null,
+            downvoteStake: null,
+            categoryVoterRules: null,
+          })
+          .accounts({ config: configPDA, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Should have failed due to invalid thresholds");
+      } catch (error) {
+        expect(error.message).to.include("InvalidConfigurationValues");
+      }
+    });
+  });
+
+  describe("Token-Gated Voting", () => {
+    const configUpdate = (tokenGate) => ({
+      votingCooldown: null,
+      minAccountAge: null,
+      dailyVoteLimit: null,
+      minReputationToVote: null,
+      categoryWeights: null,
+      roleThresholds: null,
+      decayRate: null,
+      decayEnabled: null,
+      voteHistoryDepth: null,
+      voteAmendWindow: null,
+      pairDailyVoteLimit: null,
+      votingPower: null,
+      commitReveal: null,
+      diminishingReturns: null,
+      collusion: null,
+      tokenGate,
+      downvoteStake: null,
+      categoryVoterRules: null,
+    });
+
+    let governanceMint: PublicKey;
+    let user1TokenAccount: PublicKey;
+
+    before(async () => {
+      governanceMint = await createMint(provider.connection, admin, admin.publicKey, null, 0);
+      user1TokenAccount = (
+        await getOrCreateAssociatedTokenAccount(provider.connection, admin, governanceMint, user1.publicKey)
+      ).address;
+      await mintTo(provider.connection, admin, governanceMint, user1TokenAccount, admin, 100);
+    });
+
+    it("Should reject an enabled gate without a minimum balance", async () => {
+      try {
+        await program.methods
+          .updateConfig(configUpdate({ mode: { voting: {} }, mint: governanceMint, minBalance: new anchor.BN(0) }))
+          .accounts({ config: configPDA, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Should have failed due to missing minimum balance");
+      } catch (error) {
+        expect(error.message).to.include("InvalidConfigurationValues");
+      }
+    });
+
+    it("Should require voters to hold the minimum balance", async () => {
+      await program.methods
+        .updateConfig(configUpdate({ mode: { voting: {} }, mint: governanceMint, minBalance: new anchor.BN(500) }))
+        .accounts({ config: configPDA, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+
+      const castVote = (voterTokenAccount: PublicKey | null) =>
+        program.methods
+          .castVote(true, { governance: {} }, 1)
+          .accounts({
+            config: configPDA,
+            leaderboards: leaderboardAccounts,
+            seasonData: currentSeasonDataPDA,
+            voterReputation: user1ReputationPDA,
+            targetReputation: user2ReputationPDA,
+            votingRecord: votingRecordPDA,
+            voterSummary: counterpartSummaryFor(user1.publicKey),
+            targetSummary: counterpartSummaryFor(user2.publicKey),
+            collusionRegistry: collusionRegistryPDA,
+            voterTokenAccount,
+            downvoteStake: noDownvoteStake,
+            delegation: null,
+            voter: user1.publicKey,
+            authority: user1.publicKey,
+            target: user2.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+
+      try {
+        await castVote(null);
+        expect.fail("Should have failed without a token account");
+      } catch (error) {
+        expect(error.message).to.include("TokenAccountRequired");
+      }
+
+      try {
+        await castVote(user1TokenAccount); // holds 100 of the required 500
+        expect.fail("Should have failed due to the token balance");
+      } catch (error) {
+        expect(error.message).to.include("InsufficientTokenBalance");
+      }
+
+      // Amending the revealed vote on user3 is gated like a new vote
+      try {
+        await program.methods
+          .amendVote(false, { governance: {} }, 3)
+          .accounts({
+            config: configPDA,
+            voterReputation: user1ReputationPDA,
+            targetReputation: user3ReputationPDA,
+            votingRecord: PublicKey.findProgramAddressSync(
+              [Buffer.from("voting_record"), user1.publicKey.toBuffer(), user3.publicKey.toBuffer()],
+              program.programId
+            )[0],
+            voterSummary: counterpartSummaryFor(user1.publicKey),
+            targetSummary: counterpartSummaryFor(user3.publicKey),
+            collusionRegistry: collusionRegistryPDA,
+            leaderboards: leaderboardAccounts,
+            seasonData: currentSeasonDataPDA,
+            voterTokenAccount: null,
+            downvoteStake: noDownvoteStake,
+            stakeRecipient: null,
+            voter: user1.publicKey,
+            target: user3.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+
+        expect.fail("Should have failed without a token account");
+      } catch (error) {
+        expect(error.message).to.include("TokenAccountRequired");
+      }
+    });
+
+    it("Should let token holders register when registration is gated", async () => {
+      await program.methods
+        .updateConfig(configUpdate({
+          mode: { votingAndRegistration: {} },
+          mint: governanceMint,
+          minBalance: new anchor.BN(50),
+        }))
+        .accounts({ config: configPDA, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+
+      const holder = Keypair.generate();
+      await provider.connection.requestAirdrop(holder.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+      const holderTokenAccount = (
+        await getOrCreateAssociatedTokenAccount(provider.connection, admin, governanceMint, holder.publicKey)
+      ).address;
+      const [holderReputationPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_reputation"), holder.publicKey.toBuffer()],
+        program.programId
+      );
+      const register = () =>
+        program.methods
+          .initializeUserReputation()
+          .accounts({
+            config: configPDA,
+            rankIndex: rankIndex.publicKey,
+            userReputation: holderReputationPDA,
+            userTokenAccount: holderTokenAccount,
+            user: holder.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([holder])
+          .rpc();
+
+      try {
+        await register();
+        expect.fail("Should have failed with an empty token account");
+      } catch (error) {
+        expect(error.message).to.include("InsufficientTokenBalance");
+      }
+
+      await mintTo(provider.connection, admin, governanceMint, holderTokenAccount, admin, 50);
+      try {
+        await register();
+
+        const holderReputation = await program.account.userReputation.fetch(holderReputationPDA);
+        expect(holderReputation.user.toString()).to.equal(holder.publicKey.toString());
+      } finally {
+        // Open voting and registration again for the remaining tests
+        await program.methods
+          .updateConfig(configUpdate({ mode: { off: {} }, mint: governanceMint, minBalance: new anchor.BN(50) }))
+          .accounts({ config: configPDA, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+      }
+    });
+  });
+
+  describe("Stake-Backed Downvotes", () => {
+    const configUpdate = (downvoteStake) => ({
+      votingCooldown: null,
+      minAccountAge: null,
+      dailyVoteLimit: null,
+      minReputationToVote: null,
+      categoryWeights: null,
+      roleThresholds: null,
+      decayRate: null,
+      decayEnabled: null,
+      voteHistoryDepth: null,
+      voteAmendWindow: null,
+      pairDailyVoteLimit: null,
+      votingPower: null,
+      commitReveal: null,
+      diminishingReturns: null,
+      collusion: null,
+      tokenGate: null,
+      downvoteStake,
+      categoryVoterRules: null,
+    });
+    const STAKE = 10_000_000; // 0.01 SOL
+    const lamportStake = {
+      mode: { lamports: {} },
+      amount: new anchor.BN(STAKE),
+      mint: PublicKey.default,
+      challengeWindow: new anchor.BN(3600),
+      slashRecipient: { target: {} },
+      treasury: PublicKey.default,
+    };
+
+    let stakeRecordPDA: PublicKey;
+    const downvote = (downvoteStake) =>
+      program.methods
+        .castVote(false, { community: {} }, 2)
+        .accounts({
+          config: configPDA,
+          leaderboards: leaderboardAccounts,
+          seasonData: currentSeasonDataPDA,
+          voterReputation: user2ReputationPDA,
+          targetReputation: user3ReputationPDA,
+          votingRecord: stakeRecordPDA,
+          voterSummary: counterpartSummaryFor(user2.publicKey),
+          targetSummary: counterpartSummaryFor(user3.publicKey),
+          collusionRegistry: collusionRegistryPDA,
+          voterTokenAccount: null,
+          downvoteStake,
+          delegation: null,
+          voter: user2.publicKey,
+          authority: user2.publicKey,
+          target: user3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+    before(async () => {
+      [stakeRecordPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("voting_record"), user2.publicKey.toBuffer(), user3.publicKey.toBuffer()],
+        program.programId
+      );
+
+      // Give user2 enough reputation to vote
+      await program.methods
+        .updateUserReputation({ community: {} }, new anchor.BN(200), "Reputation for stake tests")
+        .accounts({
+          config: configPDA,
+          leaderboards: leaderboardAccounts,
+          userReputation: user2ReputationPDA,
+          admin: admin.publicKey,
+          user: user2.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      // user3 votes in the amend and retract tests
+      await program.methods
+        .updateUserReputation({ community: {} }, new anchor.BN(200), "Reputation for stake tests")
+        .accounts({
+          config: configPDA,
+          leaderboards: leaderboardAccounts,
+          userReputation: user3ReputationPDA,
+          admin: admin.publicKey,
+          user: user3.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    // user3 votes on `target` in the community category
+    const user3Vote = (isUpvote: boolean, target: Keypair, targetReputation: PublicKey, downvoteStake) =>
+      program.methods
+        .castVote(isUpvote, { community: {} }, 2)
+        .accounts({
+          config: configPDA,
+          leaderboards: leaderboardAccounts,
+          seasonData: currentSeasonDataPDA,
+          voterReputation: user3ReputationPDA,
+          targetReputation,
+          votingRecord: PublicKey.findProgramAddressSync(
+            [Buffer.from("voting_record"), user3.publicKey.toBuffer(), target.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          voterSummary: counterpartSummaryFor(user3.publicKey),
+          targetSummary: counterpartSummaryFor(target.publicKey),
+          collusionRegistry: collusionRegistryPDA,
+          voterTokenAccount: null,
+          downvoteStake,
+          delegation: null,
+          voter: user3.publicKey,
+          authority: user3.publicKey,
+          target: target.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user3])
+        .rpc();
+
+    const user3Amend = (
+      isUpvote: boolean,
+      target: Keypair,
+      targetReputation: PublicKey,
+      downvoteStake,
+      stakeRecipient: PublicKey | null
+    ) =>
+      program.methods
+        .amendVote(isUpvote, { community: {} }, 2)
+        .accounts({
+          config: configPDA,
+          voterReputation: user3ReputationPDA,
+          targetReputation,
+          votingRecord: PublicKey.findProgramAddressSync(
+            [Buffer.from("voting_record"), user3.publicKey.toBuffer(), target.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          voterSummary: counterpartSummaryFor(user3.publicKey),
+          targetSummary: counterpartSummaryFor(target.publicKey),
+          collusionRegistry: collusionRegistryPDA,
+          leaderboards: leaderboardAccounts,
+          seasonData: currentSeasonDataPDA,
+          voterTokenAccount: null,
+          downvoteStake,
+          stakeRecipient,
+          voter: user3.publicKey,
+          target: target.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user3])
+        .rpc();
+
+    it("Should reject staking without a challenge window", async () => {
+      try {
+        await program.methods
+          .updateConfig(configUpdate({ ...lamportStake, challengeWindow: new anchor.BN(0) }))
+          .accounts({ config: configPDA, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Should have failed due to the missing challenge window");
+      } catch (error) {
+        expect(error.message).to.include("InvalidConfigurationValues");
+      }
+    });
+
+    it("Should require the stake vault for downvotes once staking is enabled", async () => {
+      await program.methods
+        .updateConfig(configUpdate(lamportStake))
+        .accounts({ config: configPDA, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+
+      try {
+        await downvote(noDownvoteStake);
+        expect.fail("Should have failed without the stake vault");
+      } catch (error) {
+        expect(error.message).to.include("StakeAccountsMissing");
+      }
+    });
+
+    it("Should slash an overturned downvote to the target and restore its points", async () => {
+      const before = await program.account.userReputation.fetch(user3ReputationPDA);
+
+      try {
+        await downvote({ ...noDownvoteStake, stakeVault: stakeVaultPDA });
+
+        const votingRecord = await program.account.votingRecord.fetch(stakeRecordPDA);
+        expect(votingRecord.downvoteStake.amount.toNumber()).to.equal(STAKE);
+        expect(votingRecord.downvoteStake.appealed).to.be.false;
+        const stakeVault = await program.account.stakeVault.fetch(stakeVaultPDA);
+        expect(stakeVault.openStakes.toNumber()).to.equal(1);
+
+        // Unappealed stakes stay in escrow until the challenge window closes
+        try {
+          await program.methods
+            .releaseDownvoteStake()
+            .accounts({
+              config: configPDA,
+              votingRecord: stakeRecordPDA,
+              downvoteStake: { ...noDownvoteStake, stakeVault: stakeVaultPDA },
+              recipient: user2.publicKey,
+              voter: user2.publicKey,
+              target: user3.publicKey,
+            })
+            .rpc();
+
+          expect.fail("Should have failed while the challenge window is open");
+        } catch (error) {
+          expect(error.message).to.include("ChallengeWindowStillOpen");
+        }
+
+        await program.methods
+          .appealDownvote()
+          .accounts({
+            config: configPDA,
+            votingRecord: stakeRecordPDA,
+            voter: user2.publicKey,
+            target: user3.publicKey,
+          })
+          .signers([user3])
+          .rpc();
+
+        const targetLamportsBefore = await provider.connection.getBalance(user3.publicKey);
+        await program.methods
+          .resolveDownvote(true)
+          .accounts({
+            config: configPDA,
+            targetReputation: user3ReputationPDA,
+            votingRecord: stakeRecordPDA,
+            downvoteStake: { ...noDownvoteStake, stakeVault: stakeVaultPDA },
+            leaderboards: leaderboardAccounts,
+            seasonData: currentSeasonDataPDA,
+            recipient: user3.publicKey,
+            voter: user2.publicKey,
+            target: user3.publicKey,
+            admin: admin.publicKey,
+          })
+          .signers([admin])
+          .rpc();
+
+        const targetLamportsAfter = await provider.connection.getBalance(user3.publicKey);
+        expect(targetLamportsAfter - targetLamportsBefore).to.equal(STAKE);
+
+        const after = await program.account.userReputation.fetch(user3ReputationPDA);
+        expect(after.categoryPoints[2].toNumber()).to.equal(before.categoryPoints[2].toNumber());
+
+        const resolved = await program.account.votingRecord.fetch(stakeRecordPDA);
+        expect(resolved.downvoteStake.mode).to.deep.equal({ off: {} });
+        expect(resolved.voteHistory[resolved.voteHistory.length - 1].retracted).to.be.true;
+        const settledVault = await program.account.stakeVault.fetch(stakeVaultPDA);
+        expect(settledVault.openStakes.toNumber()).to.equal(0);
+      } finally {
+        // Free downvotes again for the remaining tests
+        await program.methods
+          .updateConfig(configUpdate({ ...lamportStake, mode: { off: {} } }))
+          .accounts({ config: configPDA, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+      }
+    });
+
+    it("Should stake an upvote amended into a downvote and refund it on retraction", async () => {
+      const [recordPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("voting_record"), user3.publicKey.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+      const withVault = { ...noDownvoteStake, stakeVault: stakeVaultPDA };
+
+      await program.methods
+        .updateConfig(configUpdate(lamportStake))
+        .accounts({ config: configPDA, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+
+      try {
+        await user3Vote(true, user2, user2ReputationPDA, noDownvoteStake);
+
+        try {
+          await user3Amend(false, user2, user2ReputationPDA, noDownvoteStake, null);
+          expect.fail("Should have failed without the stake");
+        } catch (error) {
+          expect(error.message).to.include("StakeAccountsMissing");
+        }
+
+        await user3Amend(false, user2, user2ReputationPDA, withVault, null);
+
+        // The stake is tied to the vote's original cast time
+        const amended = await program.account.votingRecord.fetch(recordPDA);
+        const downvote = amended.voteHistory[amended.voteHistory.length - 1];
+        expect(downvote.isUpvote).to.be.false;
+        expect(amended.downvoteStake.amount.toNumber()).to.equal(STAKE);
+        expect(amended.downvoteStake.stakedAt.toNumber()).to.equal(downvote.timestamp.toNumber());
+
+        const voterLamportsBefore = await provider.connection.getBalance(user3.publicKey);
+        await program.methods
+          .retractVote()
+          .accounts({
+            config: configPDA,
+            targetReputation: user2ReputationPDA,
+            votingRecord: recordPDA,
+            leaderboards: leaderboardAccounts,
+            seasonData: currentSeasonDataPDA,
+            downvoteStake: withVault,
+            stakeRecipient: user3.publicKey,
+            voter: user3.publicKey,
+            target: user2.publicKey,
+          })
+          .signers([user3])
+          .rpc();
+
+        const voterLamportsAfter = await provider.connection.getBalance(user3.publicKey);
+        expect(voterLamportsAfter - voterLamportsBefore).to.equal(STAKE);
+        const retracted = await program.account.votingRecord.fetch(recordPDA);
+        expect(retracted.downvoteStake.mode).to.deep.equal({ off: {} });
+        const stakeVault = await program.account.stakeVault.fetch(stakeVaultPDA);
+        expect(stakeVault.openStakes.toNumber()).to.equal(0);
+      } finally {
+        await program.methods
+          .updateConfig(configUpdate({ ...lamportStake, mode: { off: {} } }))
+          .accounts({ config: configPDA, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+      }
+    });
+
+    it("Should slash an appealed downvote amended into an upvote", async () => {
+      const [recordPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("voting_record"), user3.publicKey.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      const withVault = { ...noDownvoteStake, stakeVault: stakeVaultPDA };
+
+      await program.methods
+        .updateConfig(configUpdate(lamportStake))
+        .accounts({ config: configPDA, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+
+      try {
+        await user3Vote(false, user1, user1ReputationPDA, withVault);
+        await program.methods
+          .appealDownvote()
+          .accounts({
+            config: configPDA,
+            votingRecord: recordPDA,
+            voter: user3.publicKey,
+            target: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+
+        // Amending away an appealed downvote concedes the appeal
+        const targetLamportsBefore = await provider.connection.getBalance(user1.publicKey);
+        await user3Amend(true, user1, user1ReputationPDA, withVault, user1.publicKey);
+
+        const targetLamportsAfter = await provider.connection.getBalance(user1.publicKey);
+        expect(targetLamportsAfter - targetLamportsBefore).to.equal(STAKE);
+        const amended = await program.account.votingRecord.fetch(recordPDA);
+        expect(amended.downvoteStake.mode).to.deep.equal({ off: {} });
+        expect(amended.voteHistory[amended.voteHistory.length - 1].isUpvote).to.be.true;
+      } finally {
+        await program.methods
+          .updateConfig(configUpdate({ ...lamportStake, mode: { off: {} } }))
+          .accounts({ config: configPDA, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+      }
+    });
+  });
+
+  describe("Vote Delegation", () => {
+    let delegationPDA: PublicKey;
+    let delegatedRecordPDA: PublicKey;
+
+    // user3 votes on user1 with user2's reputation
+    const delegatedVote = (category, delegation: PublicKey | null) =>
+      program.methods
+        .castVote(true, category, 2)
+        .accounts({
+          config: configPDA,
+          leaderboards: leaderboardAccounts,
+          seasonData: currentSeasonDataPDA,
+          voterReputation: user2ReputationPDA,
+          targetReputation: user1ReputationPDA,
+          votingRecord: delegatedRecordPDA,
+          voterSummary: counterpartSummaryFor(user2.publicKey),
+          targetSummary: counterpartSummaryFor(user1.publicKey),
+          collusionRegistry: collusionRegistryPDA,
+          voterTokenAccount: null,
+          downvoteStake: noDownvoteStake,
+          delegation,
+          voter: user2.publicKey,
+          authority: user3.publicKey,
+          target: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user3])
+        .rpc();
+
+    before(() => {
+      [delegationPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("delegation"), user2.publicKey.toBuffer(), user3.publicKey.toBuffer()],
+        program.programId
+      );
+      [delegatedRecordPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("voting_record"), user2.publicKey.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+    });
+
+    it("Should reject delegating to yourself", async () => {
+      try {
+        await program.methods
+          .delegateVotes([false, false, true, false], new anchor.BN(0))
+          .accounts({
+            delegatorReputation: user2ReputationPDA,
+            delegateReputation: user2ReputationPDA,
+            delegation: PublicKey.findProgramAddressSync(
+              [Buffer.from("delegation"), user2.publicKey.toBuffer(), user2.publicKey.toBuffer()],
+              program.programId
+            )[0],
+            delegator: user2.publicKey,
+            delegate: user2.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user2])
+          .rpc();
+
+        expect.fail("Should have failed when delegating to self");
+      } catch (error) {
+        expect(error.message).to.include("CannotDelegateToSelf");
+      }
+    });
+
+    it("Should delegate community votes to a trusted member", async () => {
+      await program.methods
+        .delegateVotes([false, false, true, false], new anchor.BN(0))
+        .accounts({
+          delegatorReputation: user2ReputationPDA,
+          delegateReputation: user3ReputationPDA,
+          delegation: delegationPDA,
+          delegator: user2.publicKey,
+          delegate: user3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      const delegation = await program.account.delegation.fetch(delegationPDA);
+      expect(delegation.delegator.toString()).to.equal(user2.publicKey.toString());
+      expect(delegation.delegate.toString()).to.equal(user3.publicKey.toString());
+      expect(delegation.categories).to.deep.equal([false, false, true, false]);
+      expect(delegation.expiresAt.toNumber()).to.equal(0);
+    });
+
+    it("Should only let the delegate vote in delegated categories", async () => {
+      try {
+        await delegatedVote({ development: {} }, delegationPDA);
+        expect.fail("Should have failed outside the delegated categories");
+      } catch (error) {
+        expect(error.message).to.include("DelegationNotActive");
+      }
+
+      const voterBefore = await program.account.userReputation.fetch(user2ReputationPDA);
+      const targetBefore = await program.account.userReputation.fetch(user1ReputationPDA);
+
+      await delegatedVote({ community: {} }, delegationPDA);
+
+      // The vote is cast with the delegator's reputation and counts against their limits
+      const votingRecord = await program.account.votingRecord.fetch(delegatedRecordPDA);
+      expect(votingRecord.voter.toString()).to.equal(user2.publicKey.toString());
+      expect(votingRecord.dailyVotes).to.equal(1);
+
+      const voterAfter = await program.account.userReputation.fetch(user2ReputationPDA);
+      expect(voterAfter.votesCast.toNumber()).to.equal(voterBefore.votesCast.toNumber() + 1);
+      const targetAfter = await program.account.userReputation.fetch(user1ReputationPDA);
+      expect(targetAfter.categoryPoints[2].toNumber()).to.be.greaterThan(targetBefore.categoryPoints[2].toNumber());
+    });
+
+    it("Should apply the delegator's cooldown to delegated votes", async () => {
+      try {
+        await delegatedVote({ community: {} }, delegationPDA);
+        expect.fail("Should have failed due to the delegator's cooldown");
+      } catch (error) {
+        expect(error.message).to.include("VotingCooldownNotExpired");
+      }
+    });
+
+    it("Should stop delegated votes once revoked", async () => {
+      await program.methods
+        .revokeDelegation()
+        .accounts({
+          delegation: delegationPDA,
+          delegator: user2.publicKey,
+          delegate: user3.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      const closed = await provider.connection.getAccountInfo(delegationPDA);
+      expect(closed).to.be.null;
+
+      try {
+        await delegatedVote({ community: {} }, null);
+        expect.fail("Should have failed without a delegation");
+      } catch (error) {
+        expect(error.message).to.include("DelegationRequired");
+      }
+    });
+  });
+
+  describe("Category Voter Rules", () => {
+    const configUpdate = (categoryVoterRules) => ({
+      votingCooldown: null,
+      minAccountAge: null,
+      dailyVoteLimit: null,
+      minReputationToVote: null,
+      categoryWeights: null,
+      roleThresholds: null,
+      decayRate: null,
+      decayEnabled: null,
+      voteHistoryDepth: null,
+      voteAmendWindow: null,
+      pairDailyVoteLimit: null,
+      votingPower: null,
+      commitReveal: null,
+      diminishingReturns: null,
+      collusion: null,
+      tokenGate: null,
+      downvoteStake: null,
+      categoryVoterRules,
+    });
+    const openRule = { minCategoryPoints: new anchor.BN(0), minRoleLevel: 0, requiredAchievement: null };
+    const rules = [
+      openRule,
+      { ...openRule, minCategoryPoints: new anchor.BN(1000) }, // development: experienced developers only
+      openRule,
+      { ...openRule, requiredAchievement: { seasonWinner: {} } }, // treasury: season winners only
+    ];
+
+    // user1 votes on user3
+    const vote = (category) =>
+      program.methods
+        .castVote(true, category, 1)
+        .accounts({
+          config: configPDA,
+          leaderboards: leaderboardAccounts,
+          seasonData: currentSeasonDataPDA,
+          voterReputation: user1ReputationPDA,
+          targetReputation: user3ReputationPDA,
+          votingRecord: PublicKey.findProgramAddressSync(
+            [Buffer.from("voting_record"), user1.publicKey.toBuffer(), user3.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          voterSummary: counterpartSummaryFor(user1.publicKey),
+          targetSummary: counterpartSummaryFor(user3.publicKey),
+          collusionRegistry: collusionRegistryPDA,
+          voterTokenAccount: null,
+          downvoteStake: noDownvoteStake,
+          delegation: null,
+          voter: user1.publicKey,
+          authority: user1.publicKey,
+          target: user3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+    it("Should reject role levels above the highest role", async () => {
+      try {
+        await program.methods
+          .updateConfig(configUpdate([openRule, openRule, openRule, { ...openRule, minRoleLevel: 6 }]))
+          .accounts({ config: configPDA, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Should have failed due to the role level");
+      } catch (error) {
+        expect(error.message).to.include("InvalidConfigurationValues");
+      }
+    });
+
+    it("Should enforce each category's voter rule", async () => {
+      await program.methods
+        .updateConfig(configUpdate(rules))
+        .accounts({ config: configPDA, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+
+      const config = await program.account.reputationConfig.fetch(configPDA);
+      expect(config.categoryVoterRules[1].minCategoryPoints.toNumber()).to.equal(1000);
+
+      try {
+        // user1's reputation is in governance, not development
+        await vote({ development: {} });
+        expect.fail("Should have failed due to missing development points");
+      } catch (error) {
+        expect(error.message).to.include("InsufficientCategoryPoints");
+      }
+
+      try {
+        await vote({ treasury: {} });
+        expect.fail("Should have failed due to the missing achievement");
+      } catch (error) {
+        expect(error.message).to.include("MissingRequiredAchievement");
+      } finally {
+        // Open every category again for the remaining tests
+        await program.methods
+          .updateConfig(configUpdate([openRule, openRule, openRule, openRule]))
+          .accounts({ config: configPDA, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+      }
+    });
+  });
+
+  describe("Reputation Decay", () => {
+    it("Should calculate decay preview correctly", async () => {
+      // This would require mocking time passage in a real test
+      // For now, we'll just verify the instruction executes
+      const result = await program.methods
+        .calculateDecayPreview(user2.publicKey)
+        .accounts({
+          config: configPDA,
+          userReputation: user2ReputationPDA,
+        })
+        .view();
+
+      expect(result.user.toString()).to.equal(user2.publicKey.toString());
+      expect(result.decayEnabled).to.be.true;
+    });
+
+    it("Should allow admin to reset decay timer", async () => {
+      await program.methods
+        .resetDecayTimer(user2.publicKey)
+        .accounts({
+          config: configPDA,
+          userReputation: user2ReputationPDA,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      // Verify last activity was updated
+      const user2Rep = await program.account.userReputation.fetch(user2ReputationPDA);
+      expect(user2Rep.lastActivity.toNumber()).to.be.greaterThan(0);
+    });
+  });
+
+  describe("Streak System", () => {
+    it("Should update user streak correctly", async () => {
+      await program.methods
+        .updateUserStreak(user1.publicKey)
+        .accounts({
+          config: configPDA,
+          userReputation: user1ReputationPDA,
+          leaderboards: leaderboardAccounts,
+          streakLeaderboard: streakLeaderboardPDA,
+        })
+        .rpc();
+
+      const user1Rep = await program.account.userReputation.fetch(user1ReputationPDA);
+      expect(user1Rep.currentStreak).to.be.greaterThan(0);
+    });
+
+    it("Should rank real users on the streak leaderboard", async () => {
+      await program.methods
+        .resetUserStreak(user2.publicKey, 12)
+        .accounts({
+          config: configPDA,
+          userReputation: user2ReputationPDA,
+          streakLeaderboard: streakLeaderboardPDA,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      const current = await program.methods
+        .getStreakLeaderboard({ current: {} }, 0, 10)
+        .accounts({ streakLeaderboard: streakLeaderboardPDA })
+        .view();
+
+      expect(current[0].user.toString()).to.equal(user2.publicKey.toString());
+      expect(current[0].streakValue).to.equal(12);
+      expect(current[0].rank).to.equal(1);
+      expect(current.map(entry => entry.user.toString())).to.include(user1.publicKey.toString());
+
+      const longest = await program.methods
+        .getStreakLeaderboard({ longest: {} }, 0, 10)
+        .accounts({ streakLeaderboard: streakLeaderboardPDA })
+        .view();
+
+      expect(longest[0].longestStreak).to.equal(12);
+
+      // Active only lists users whose streak has not lapsed
+      const active = await program.methods
+        .getStreakLeaderboard({ active: {} }, 0, 10)
+        .accounts({ streakLeaderboard: streakLeaderboardPDA })
+        .view();
+
+      active.forEach(entry => expect(entry.isActive).to.be.true);
+    });
+
+    it("Should paginate the streak leaderboard", async () => {
+      const firstPage = await program.methods
+        .getStreakLeaderboard({ current: {} }, 0, 1)
+        .accounts({ streakLeaderboard: streakLeaderboardPDA })
+        .view();
+      const secondPage = await program.methods
+        .getStreakLeaderboard({ current: {} }, 1, 1)
+        .accounts({ streakLeaderboard: streakLeaderboardPDA })
+        .view();
+
+      expect(firstPage.length).to.equal(1);
+      expect(secondPage.length).to.equal(1);
+      expect(secondPage[0].rank).to.equal(2);
+      expect(secondPage[0].user.toString()).to.not.equal(firstPage[0].user.toString());
+    });
+
+    it("Should get streak information", async () => {
+      const streakInfo = await program.methods
+        .getUserStreakInfo()
+        .accounts({
+          userReputation: user1ReputationPDA,
+          user: user1.publicKey,
+        })
+        .view();
+
+      expect(streakInfo.user.toString()).to.equal(user1.publicKey.toString());
+      expect(streakInfo.currentStreak).to.be.greaterThan(0);
+    });
+  });
 
   describe("Export Functionality", () => {
     it("Should export reputation certificate", async () => {
@@ -601,48 +2435,355 @@ describe("DAO Reputation Scoreboard", () => {
       const certificate = await program.methods
         .exportReputation()
         .accounts({
-          userReputation: user1ReputationPDA,
-          user: user1.publicKey,
+          userReputation: user1ReputationPDA,
+          user: user1.publicKey,
+        })
+        .view();
+
+      // Then verify it
+      const isValid = await program.methods
+        .verifyReputationCertificate(certificate)
+        .accounts({})
+        .view();
+
+      expect(isValid).to.be.true;
+    });
+  });
+
+  describe("Bulk Operations", () => {
+    // Hash chain a batch job commits to: h = sha256(h || borsh(chunk)), starting from zeros
+    const batchEntriesHash = (entries: PublicKey[]) =>
+      Array.from(
+        entries.reduce(
+          (chain, entry) => createHash("sha256").update(Buffer.concat([chain, entry.toBuffer()])).digest(),
+          Buffer.alloc(32)
+        )
+      );
+
+    it("Should allow bulk reputation updates", async () => {
+      const updates = [
+        {
+          user: user1.publicKey,
+          categoryPoints: [new anchor.BN(400), new anchor.BN(100), new anchor.BN(100), new anchor.BN(0)],
+          achievements: 1,
+          roleLevel: 2,
+        },
+        {
+          user: user2.publicKey,
+          categoryPoints: [new anchor.BN(900), new anchor.BN(150), new anchor.BN(0), new anchor.BN(25)],
+          achievements: 0,
+          roleLevel: 3,
+        },
+      ];
+
+      const remainingAccounts = [user1ReputationPDA, user2ReputationPDA].map(pubkey => ({
+        pubkey,
+        isWritable: true,
+        isSigner: false,
+      }));
+
+      const result = await program.methods
+        .bulkUpdateReputation(updates, 0)
+        .accounts({
+          config: configPDA,
+          leaderboards: leaderboardAccounts,
+          admin: admin.publicKey,
+          batchJob: null,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers([admin])
+        .view();
+      expect(result.succeeded).to.equal(2);
+      expect(result.results).to.deep.equal([true, true]);
+
+      await program.methods
+        .bulkUpdateReputation(updates, 0)
+        .accounts({
+          config: configPDA,
+          leaderboards: leaderboardAccounts,
+          admin: admin.publicKey,
+          batchJob: null,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers([admin])
+        .rpc();
+
+      const user2Rep = await program.account.userReputation.fetch(user2ReputationPDA);
+      expect(user2Rep.categoryPoints[0].toNumber()).to.equal(900);
+      expect(user2Rep.roleLevel).to.equal(3);
+      expect(user2Rep.totalScore.toNumber()).to.be.greaterThan(0);
+    });
+
+    it("Should bulk initialize new users and skip existing ones", async () => {
+      bulkUsers = [Keypair.generate(), Keypair.generate()];
+      const users = [user1.publicKey, ...bulkUsers.map(user => user.publicKey)];
+      const configBefore = await program.account.reputationConfig.fetch(configPDA);
+
+      await program.methods
+        .bulkInitializeUsers(users, 0)
+        .accounts({
+          config: configPDA,
+          rankIndex: rankIndex.publicKey,
+          admin: admin.publicKey,
+          batchJob: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(users.map(user => ({
+          pubkey: PublicKey.findProgramAddressSync(
+            [Buffer.from("user_reputation"), user.toBuffer()],
+            program.programId
+          )[0],
+          isWritable: true,
+          isSigner: false,
+        })))
+        .signers([admin])
+        .rpc();
+
+      // user1 already existed, so only the two new accounts count
+      const configAfter = await program.account.reputationConfig.fetch(configPDA);
+      expect(configAfter.totalUsers.toNumber()).to.equal(configBefore.totalUsers.toNumber() + 2);
+
+      const created = await program.account.userReputation.fetch(
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("user_reputation"), bulkUsers[0].publicKey.toBuffer()],
+          program.programId
+        )[0]
+      );
+      expect(created.user.toString()).to.equal(bulkUsers[0].publicKey.toString());
+      expect(created.totalScore.toNumber()).to.equal(0);
+    });
+
+    it("Should skip recently active users when applying bulk decay", async () => {
+      const summary = await program.methods
+        .bulkApplyDecay([user1.publicKey, user2.publicKey], 0)
+        .accounts({
+          config: configPDA,
+          leaderboards: leaderboardAccounts,
+          admin: admin.publicKey,
+          batchJob: null,
+        })
+        .remainingAccounts([user1ReputationPDA, user2ReputationPDA].map(pubkey => ({
+          pubkey,
+          isWritable: true,
+          isSigner: false,
+        })))
+        .signers([admin])
+        .view();
+
+      // Both users were active today, so nothing is decayed yet
+      expect(summary.totalPointsDecayed.toNumber()).to.equal(0);
+      expect(summary.outcome.results).to.deep.equal([false, false]);
+    });
+
+    it("Should track a decay run split over several calls in a batch job", async () => {
+      const jobId = new anchor.BN(1);
+      const batchJobPDA = PublicKey.findProgramAddressSync(
+        [Buffer.from("batch_job"), jobId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+
+      await program.methods
+        .createBatchJob(jobId, { decay: {} }, {
+          totalEntries: 2,
+          seasonId: 0,
+          entriesHash: batchEntriesHash([user1.publicKey, user2.publicKey]),
+        })
+        .accounts({
+          config: configPDA,
+          batchJob: batchJobPDA,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const decayChunk = (user: Keypair, reputationPDA: PublicKey, startIndex: number) =>
+        program.methods
+          .bulkApplyDecay([user.publicKey], startIndex)
+          .accounts({
+            config: configPDA,
+            leaderboards: leaderboardAccounts,
+            admin: admin.publicKey,
+            batchJob: batchJobPDA,
+          })
+          .remainingAccounts([{ pubkey: reputationPDA, isWritable: true, isSigner: false }])
+          .signers([admin])
+          .rpc();
+
+      await decayChunk(user1, user1ReputationPDA, 0);
+      let batchJob = await program.account.batchJob.fetch(batchJobPDA);
+      expect(batchJob.cursor).to.equal(1);
+      expect(batchJob.status).to.deep.equal({ active: {} });
+
+      try {
+        await decayChunk(user1, user1ReputationPDA, 0);
+        expect.fail("Should have failed because the chunk was already processed");
+      } catch (error) {
+        expect(error.message).to.include("BatchJobOutOfOrder");
+      }
+
+      try {
+        await decayChunk(user3, user3ReputationPDA, 1);
+        expect.fail("Should have failed because user3 was not committed to the job");
+      } catch (error) {
+        expect(error.message).to.include("BatchJobEntriesMismatch");
+      }
+
+      await decayChunk(user2, user2ReputationPDA, 1);
+      batchJob = await program.account.batchJob.fetch(batchJobPDA);
+      expect(batchJob.cursor).to.equal(2);
+      expect(batchJob.failedCount).to.equal(2); // both users were active today
+      expect(batchJob.status).to.deep.equal({ completed: {} });
+
+      try {
+        await decayChunk(user3, user3ReputationPDA, 2);
+        expect.fail("Should have failed because the job is completed");
+      } catch (error) {
+        expect(error.message).to.include("BatchJobNotActive");
+      }
+    });
+
+    it("Should reject a batch job created for another operation", async () => {
+      const jobId = new anchor.BN(2);
+      const batchJobPDA = PublicKey.findProgramAddressSync(
+        [Buffer.from("batch_job"), jobId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+
+      await program.methods
+        .createBatchJob(jobId, { seasonalReset: {} }, {
+          totalEntries: 10,
+          seasonId: 2,
+          entriesHash: Array(32).fill(0),
+        })
+        .accounts({
+          config: configPDA,
+          batchJob: batchJobPDA,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      try {
+        await program.methods
+          .bulkApplyDecay([user1.publicKey], 0)
+          .accounts({
+            config: configPDA,
+            leaderboards: leaderboardAccounts,
+            admin: admin.publicKey,
+            batchJob: batchJobPDA,
+          })
+          .remainingAccounts([{ pubkey: user1ReputationPDA, isWritable: true, isSigner: false }])
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Should have failed due to a job kind mismatch");
+      } catch (error) {
+        expect(error.message).to.include("BatchJobMismatch");
+      }
+
+      await program.methods
+        .cancelBatchJob(jobId)
+        .accounts({
+          config: configPDA,
+          batchJob: batchJobPDA,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      const batchJob = await program.account.batchJob.fetch(batchJobPDA);
+      expect(batchJob.status).to.deep.equal({ cancelled: {} });
+    });
+
+    it("Should bulk award achievements and skip duplicates and future seasons", async () => {
+      const awardedAt = new anchor.BN(Math.floor(Date.now() / 1000) - 3600);
+      const awards = [
+        { user: user2.publicKey, achievement: { seasonWinner: {} }, awardedAt, seasonId: 1 },
+        { user: user1.publicKey, achievement: { firstVote: {} }, awardedAt, seasonId: 1 }, // already earned
+        { user: user3.publicKey, achievement: { seasonWinner: {} }, awardedAt, seasonId: 999 }, // future season
+      ];
+
+      await program.methods
+        .bulkAwardAchievements(awards, 0)
+        .accounts({
+          config: configPDA,
+          leaderboards: leaderboardAccounts,
+          admin: admin.publicKey,
+          batchJob: null,
+        })
+        .remainingAccounts([user2ReputationPDA, user1ReputationPDA, user3ReputationPDA].map(pubkey => ({
+          pubkey,
+          isWritable: true,
+          isSigner: false,
+        })))
+        .signers([admin])
+        .rpc();
+
+      // The award keeps the ceremony's timestamp and season
+      const seasonWinner = 7;
+      const user2Rep = await program.account.userReputation.fetch(user2ReputationPDA);
+      expect((user2Rep.achievements >> seasonWinner) & 1).to.equal(1);
+      expect(user2Rep.achievementAwardedAt[seasonWinner].toNumber()).to.equal(awardedAt.toNumber());
+      expect(user2Rep.achievementSeasons[seasonWinner]).to.equal(1);
+
+      const user3Rep = await program.account.userReputation.fetch(user3ReputationPDA);
+      expect((user3Rep.achievements >> seasonWinner) & 1).to.equal(0);
+    });
+
+    it("Should skip users whose activity is already settled", async () => {
+      const before = await program.account.userReputation.fetch(user1ReputationPDA);
+      const bulkUserPDA = PublicKey.findProgramAddressSync(
+        [Buffer.from("user_reputation"), bulkUsers[0].publicKey.toBuffer()],
+        program.programId
+      )[0];
+
+      const result = await program.methods
+        .bulkUpdateStreaks([user1.publicKey, bulkUsers[0].publicKey], 0)
+        .accounts({
+          config: configPDA,
+          leaderboards: leaderboardAccounts,
+          streakLeaderboard: streakLeaderboardPDA,
+          admin: admin.publicKey,
+          batchJob: null,
         })
+        .remainingAccounts([user1ReputationPDA, bulkUserPDA].map(pubkey => ({
+          pubkey,
+          isWritable: true,
+          isSigner: false,
+        })))
+        .signers([admin])
         .view();
 
-      // Then verify it
-      const isValid = await program.methods
-        .verifyReputationCertificate(certificate)
-        .accounts({})
-        .view();
-
-      expect(isValid).to.be.true;
+      // Both users were last active today and that day is already counted
+      expect(result.results).to.deep.equal([false, false]);
+      const after = await program.account.userReputation.fetch(user1ReputationPDA);
+      expect(after.currentStreak).to.equal(before.currentStreak);
     });
-  });
-
-  describe("Bulk Operations", () => {
-    it("Should allow bulk reputation updates", async () => {
-      const updates = [
-        {
-          user: user1.publicKey,
-          category: { community: {} },
-          pointsChange: new anchor.BN(100),
-          reason: "Bulk update test 1",
-        },
-        {
-          user: user2.publicKey,
-          category: { development: {} },
-          pointsChange: new anchor.BN(150),
-          reason: "Bulk update test 2",
-        },
-      ];
 
-      await program.methods
-        .bulkUpdateReputation(updates)
+    it("Should report entries whose account does not match the user", async () => {
+      const result = await program.methods
+        .bulkUpdateReputation([
+          {
+            user: user1.publicKey,
+            categoryPoints: [new anchor.BN(1), new anchor.BN(1), new anchor.BN(1), new anchor.BN(1)],
+            achievements: 0,
+            roleLevel: 1,
+          },
+        ], 0)
         .accounts({
           config: configPDA,
+          leaderboards: leaderboardAccounts,
           admin: admin.publicKey,
+          batchJob: null,
         })
+        .remainingAccounts([{ pubkey: user2ReputationPDA, isWritable: true, isSigner: false }])
         .signers([admin])
-        .rpc();
+        .view();
 
-      // Verify operation completed (would need actual implementation to verify changes)
+      expect(result.failed).to.equal(1);
+      expect(result.results).to.deep.equal([false]);
     });
 
     it("Should prevent oversized bulk operations", async () => {
@@ -656,10 +2797,11 @@ describe("DAO Reputation Scoreboard", () => {
 
       try {
         await program.methods
-          .bulkUpdateReputation(updates)
+          .bulkUpdateReputation(updates, 0)
           .accounts({
             config: configPDA,
             admin: admin.publicKey,
+            batchJob: null,
           })
           .signers([admin])
           .rpc();
@@ -677,11 +2819,79 @@ describe("DAO Reputation Scoreboard", () => {
         .getLeaderboard(null, 0, 10)
         .accounts({
           config: configPDA,
+          leaderboard: leaderboardPDA,
         })
         .view();
 
       expect(leaderboard).to.be.an("array");
       expect(leaderboard.length).to.be.greaterThan(0);
+
+      // Entries are real users sorted by descending score
+      for (let i = 0; i < leaderboard.length; i++) {
+        expect(leaderboard[i].user.toString()).to.not.equal(PublicKey.default.toString());
+        expect(leaderboard[i].rank).to.equal(i + 1);
+        if (i > 0) {
+          expect(leaderboard[i - 1].score.toNumber()).to.be.at.least(leaderboard[i].score.toNumber());
+        }
+      }
+    });
+
+    it("Should return the exact rank and population for a user", async () => {
+      const ranking = await program.methods
+        .getUserRanking()
+        .accounts({
+          config: configPDA,
+          userReputation: user2ReputationPDA,
+          rankIndex: rankIndex.publicKey,
+          user: user2.publicKey,
+        })
+        .view();
+
+      const config = await program.account.reputationConfig.fetch(configPDA);
+      expect(ranking.totalRanked).to.equal(config.totalUsers.toNumber());
+      expect(ranking.rank).to.be.at.least(1);
+      expect(ranking.rank).to.be.at.most(ranking.totalRanked);
+
+      // Nobody on the overall leaderboard can outrank a user with a higher score
+      const leaderboard = await program.account.leaderboard.fetch(leaderboardPDA);
+      const higherScores = leaderboard.entries
+        .slice(0, leaderboard.count)
+        .filter((entry: any) => entry.score.toNumber() > ranking.totalScore.toNumber()).length;
+      expect(ranking.rank).to.be.at.least(higherScores + 1);
+    });
+
+    it("Should rank users by category points", async () => {
+      const leaderboard = await program.methods
+        .getLeaderboard({ governance: {} }, 0, 10)
+        .accounts({
+          config: configPDA,
+          leaderboard: categoryLeaderboardPDAs[0],
+        })
+        .view();
+
+      expect(leaderboard.length).to.be.greaterThan(0);
+      for (let i = 0; i < leaderboard.length; i++) {
+        expect(leaderboard[i].category).to.deep.equal({ governance: {} });
+        if (i > 0) {
+          expect(leaderboard[i - 1].score.toNumber()).to.be.at.least(leaderboard[i].score.toNumber());
+        }
+      }
+    });
+
+    it("Should reject a board that does not match the category filter", async () => {
+      try {
+        await program.methods
+          .getLeaderboard({ development: {} }, 0, 10)
+          .accounts({
+            config: configPDA,
+            leaderboard: leaderboardPDA,
+          })
+          .view();
+
+        expect.fail("Should have failed due to category mismatch");
+      } catch (error) {
+        expect(error.message).to.include("InvalidReputationCategory");
+      }
     });
 
     it("Should prevent invalid pagination parameters", async () => {
@@ -690,6 +2900,7 @@ describe("DAO Reputation Scoreboard", () => {
           .getLeaderboard(null, 0, 0) // Invalid page size
           .accounts({
             config: configPDA,
+            leaderboard: leaderboardPDA,
           })
           .view();
         
@@ -698,6 +2909,95 @@ describe("DAO Reputation Scoreboard", () => {
         expect(error.message).to.include("InvalidPaginationParameters");
       }
     });
+
+    it("Should reject crank accounts that are not in ascending user order", async () => {
+      const users = [user1, user2, user3]
+        .map(user => user.publicKey)
+        .sort((a, b) => Buffer.compare(a.toBuffer(), b.toBuffer()))
+        .reverse();
+
+      try {
+        await program.methods
+          .updateLeaderboardRankings()
+          .accounts({
+            config: configPDA,
+            rankingCrank: rankingCrankPDA,
+            leaderboards: leaderboardAccounts,
+            cranker: user3.publicKey,
+          })
+          .remainingAccounts(users.map(user => ({
+            pubkey: PublicKey.findProgramAddressSync(
+              [Buffer.from("user_reputation"), user.toBuffer()],
+              program.programId
+            )[0],
+            isWritable: true,
+            isSigner: false,
+          })))
+          .signers([user3])
+          .rpc();
+
+        expect.fail("Should have failed due to crank ordering");
+      } catch (error) {
+        expect(error.message).to.include("CrankOutOfOrder");
+      }
+    });
+
+    it("Should reject read-only crank accounts", async () => {
+      try {
+        await program.methods
+          .updateLeaderboardRankings()
+          .accounts({
+            config: configPDA,
+            rankingCrank: rankingCrankPDA,
+            leaderboards: leaderboardAccounts,
+            cranker: user3.publicKey,
+          })
+          .remainingAccounts([{ pubkey: user1ReputationPDA, isWritable: false, isSigner: false }])
+          .signers([user3])
+          .rpc();
+
+        expect.fail("Should have failed due to a read-only crank account");
+      } catch (error) {
+        expect(error.message).to.include("InvalidCrankAccount");
+      }
+    });
+
+    it("Should let anyone sweep all users and commit a ranking snapshot", async () => {
+      // Every registered user, including bulk-initialized ones, must be swept
+      const users = [user1, user2, user3, ...bulkUsers]
+        .map(user => user.publicKey)
+        .sort((a, b) => Buffer.compare(a.toBuffer(), b.toBuffer()));
+      const userAccounts = users.map(user => ({
+        pubkey: PublicKey.findProgramAddressSync(
+          [Buffer.from("user_reputation"), user.toBuffer()],
+          program.programId
+        )[0],
+        isWritable: true,
+        isSigner: false,
+      }));
+
+      // Two chunks: the cursor carries the sweep across calls
+      for (const chunk of [userAccounts.slice(0, 2), userAccounts.slice(2)]) {
+        await program.methods
+          .updateLeaderboardRankings()
+          .accounts({
+            config: configPDA,
+            rankingCrank: rankingCrankPDA,
+            leaderboards: leaderboardAccounts,
+            cranker: user3.publicKey,
+          })
+          .remainingAccounts(chunk)
+          .signers([user3])
+          .rpc();
+      }
+
+      const rankingCrank = await program.account.rankingCrank.fetch(rankingCrankPDA);
+      const leaderboard = await program.account.leaderboard.fetch(leaderboardPDA);
+      expect(rankingCrank.completedSweeps.toNumber()).to.equal(1);
+      expect(rankingCrank.inProgress).to.equal(0);
+      expect(rankingCrank.snapshotCount).to.equal(leaderboard.count);
+      expect(rankingCrank.snapshot[0].user.toString()).to.equal(leaderboard.entries[0].user.toString());
+    });
   });
 
   describe("Anti-Abuse Mechanisms", () => {
@@ -717,7 +3017,9 @@ describe("DAO Reputation Scoreboard", () => {
         .initializeUserReputation()
         .accounts({
           config: configPDA,
+          rankIndex: rankIndex.publicKey,
           userReputation: newUserReputationPDA,
+          userTokenAccount: null,
           user: newUser.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -735,10 +3037,19 @@ describe("DAO Reputation Scoreboard", () => {
           .castVote(true, { governance: {} }, 5)
           .accounts({
             config: configPDA,
+            leaderboards: leaderboardAccounts,
+            seasonData: currentSeasonDataPDA,
             voterReputation: newUserReputationPDA,
             targetReputation: user1ReputationPDA,
             votingRecord: newVotingRecordPDA,
+            voterSummary: counterpartSummaryFor(newUser.publicKey),
+            targetSummary: counterpartSummaryFor(user1.publicKey),
+            collusionRegistry: collusionRegistryPDA,
+            voterTokenAccount: null,
+            downvoteStake: noDownvoteStake,
+            delegation: null,
             voter: newUser.publicKey,
+            authority: newUser.publicKey,
             target: user1.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -752,11 +3063,77 @@ describe("DAO Reputation Scoreboard", () => {
     });
 
     it("Should enforce daily vote limits", async () => {
-      // This test would require multiple votes in succession
-      // In practice, you'd need to mock time or have a way to reset daily limits
-      // For demonstration, we'll just verify the voting record structure
+      // The pair counter and the voter's global counter both track today's votes
       const votingRecord = await program.account.votingRecord.fetch(votingRecordPDA);
       expect(votingRecord.dailyVotes).to.be.lessThan(DAILY_VOTE_LIMIT);
+
+      const user1Rep = await program.account.userReputation.fetch(user1ReputationPDA);
+      expect(user1Rep.dailyVotesCast).to.be.at.least(votingRecord.dailyVotes);
+    });
+
+    it("Should cap a voter's daily votes across different targets", async () => {
+      const setDailyVoteLimit = (dailyVoteLimit: number) =>
+        program.methods
+          .updateConfig({
+            votingCooldown: null,
+            minAccountAge: null,
+            dailyVoteLimit,
+            minReputationToVote: null,
+            categoryWeights: null,
+            roleThresholds: null,
+            decayRate: null,
+            decayEnabled: null,
+            voteHistoryDepth: null,
+            voteAmendWindow: null,
+            pairDailyVoteLimit: null,
+            votingPower: null,
+            commitReveal: null,
+            diminishingReturns: null,
+            collusion: null,
+            tokenGate: null,
+            downvoteStake: null,
+            categoryVoterRules: null,
+          })
+          .accounts({ config: configPDA, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+
+      // user1 has already voted on user2 today, so a budget of one vote is spent
+      await setDailyVoteLimit(1);
+
+      try {
+        await program.methods
+          .castVote(true, { governance: {} }, 1)
+          .accounts({
+            config: configPDA,
+            leaderboards: leaderboardAccounts,
+            seasonData: currentSeasonDataPDA,
+            voterReputation: user1ReputationPDA,
+            targetReputation: user3ReputationPDA,
+            votingRecord: PublicKey.findProgramAddressSync(
+              [Buffer.from("voting_record"), user1.publicKey.toBuffer(), user3.publicKey.toBuffer()],
+              program.programId
+            )[0],
+            voterSummary: counterpartSummaryFor(user1.publicKey),
+            targetSummary: counterpartSummaryFor(user3.publicKey),
+            collusionRegistry: collusionRegistryPDA,
+            voterTokenAccount: null,
+            downvoteStake: noDownvoteStake,
+            delegation: null,
+            voter: user1.publicKey,
+            authority: user1.publicKey,
+            target: user3.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+
+        expect.fail("Should have failed due to the daily vote budget");
+      } catch (error) {
+        expect(error.message).to.include("DailyVoteLimitExceeded");
+      } finally {
+        await setDailyVoteLimit(15);
+      }
     });
   });
 
@@ -767,10 +3144,19 @@ describe("DAO Reputation Scoreboard", () => {
           .castVote(true, { governance: {} }, 0) // Invalid vote weight
           .accounts({
             config: configPDA,
+            leaderboards: leaderboardAccounts,
+            seasonData: currentSeasonDataPDA,
             voterReputation: user1ReputationPDA,
             targetReputation: user2ReputationPDA,
             votingRecord: votingRecordPDA,
+            voterSummary: counterpartSummaryFor(user1.publicKey),
+            targetSummary: counterpartSummaryFor(user2.publicKey),
+            collusionRegistry: collusionRegistryPDA,
+            voterTokenAccount: null,
+            downvoteStake: noDownvoteStake,
+            delegation: null,
             voter: user1.publicKey,
+            authority: user1.publicKey,
             target: user2.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -789,10 +3175,19 @@ describe("DAO Reputation Scoreboard", () => {
           .castVote(true, { governance: {} }, 11) // Weight too high
           .accounts({
             config: configPDA,
+            leaderboards: leaderboardAccounts,
+            seasonData: currentSeasonDataPDA,
             voterReputation: user1ReputationPDA,
             targetReputation: user2ReputationPDA,
             votingRecord: votingRecordPDA,
+            voterSummary: counterpartSummaryFor(user1.publicKey),
+            targetSummary: counterpartSummaryFor(user2.publicKey),
+            collusionRegistry: collusionRegistryPDA,
+            voterTokenAccount: null,
+            downvoteStake: noDownvoteStake,
+            delegation: null,
             voter: user1.publicKey,
+            authority: user1.publicKey,
             target: user2.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -817,6 +3212,7 @@ describe("DAO Reputation Scoreboard", () => {
           )
           .accounts({
             config: configPDA,
+            leaderboards: leaderboardAccounts,
             userReputation: user1ReputationPDA,
             admin: admin.publicKey,
             user: user1.publicKey,
@@ -841,6 +3237,7 @@ describe("DAO Reputation Scoreboard", () => {
           )
           .accounts({
             config: configPDA,
+            leaderboards: leaderboardAccounts,
             userReputation: user1ReputationPDA,
             admin: admin.publicKey,
             user: user1.publicKey,
@@ -868,6 +3265,7 @@ describe("DAO Reputation Scoreboard", () => {
         )
         .accounts({
           config: configPDA,
+          leaderboards: leaderboardAccounts,
           userReputation: user1ReputationPDA,
           admin: admin.publicKey,
           user: user1.publicKey,
@@ -899,6 +3297,7 @@ describe("DAO Reputation Scoreboard", () => {
         )
         .accounts({
           config: configPDA,
+          leaderboards: leaderboardAccounts,
           userReputation: user1ReputationPDA,
           admin: admin.publicKey,
           user: user1.publicKey,
@@ -930,13 +3329,22 @@ describe("DAO Reputation Scoreboard", () => {
           .castVote(true, { community: {} }, 2)
           .accounts({
             config: configPDA,
+            leaderboards: leaderboardAccounts,
+            seasonData: currentSeasonDataPDA,
             voterReputation: user1ReputationPDA,
             targetReputation: user3ReputationPDA,
             votingRecord: PublicKey.findProgramAddressSync(
               [Buffer.from("voting_record"), user1.publicKey.toBuffer(), user3.publicKey.toBuffer()],
               program.programId
             )[0],
+            voterSummary: counterpartSummaryFor(user1.publicKey),
+            targetSummary: counterpartSummaryFor(user3.publicKey),
+            collusionRegistry: collusionRegistryPDA,
+            voterTokenAccount: null,
+            downvoteStake: noDownvoteStake,
+            delegation: null,
             voter: user1.publicKey,
+            authority: user1.publicKey,
             target: user3.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -951,4 +3359,265 @@ describe("DAO Reputation Scoreboard", () => {
       expect(totalTime).to.be.lessThan(30000); // Less than 30 seconds total
     });
   });
-});
\ No newline at end of file
+
+  describe("Season Leaderboard", () => {
+    it("Should rank users by seasonal points", async () => {
+      const entries = await program.methods
+        .getSeasonLeaderboard(2, 0, 10)
+        .accounts({ seasonData: currentSeasonDataPDA })
+        .view();
+
+      const user3Rep = await program.account.userReputation.fetch(user3ReputationPDA);
+      const user3Entry = entries.find(entry => entry.user.equals(user3.publicKey));
+      expect(user3Entry).to.not.be.undefined;
+      expect(user3Rep.pointsSeason).to.equal(2);
+      const seasonData = await program.account.seasonData.fetch(currentSeasonDataPDA);
+      expect(seasonData.totalParticipants).to.be.greaterThanOrEqual(entries.length);
+      expect(user3Entry.score.toNumber()).to.equal(
+        user3Rep.seasonalPoints.reduce((sum, points) => sum + points.toNumber(), 0)
+      );
+
+      for (let i = 0; i < entries.length; i++) {
+        expect(entries[i].rank).to.equal(i + 1);
+        if (i > 0) {
+          expect(entries[i - 1].score.toNumber()).to.be.greaterThanOrEqual(entries[i].score.toNumber());
+        }
+      }
+    });
+
+    it("Should not reset seasonal data while the season is running", async () => {
+      try {
+        await program.methods
+          .resetSeasonalPoints(2)
+          .accounts({
+            config: configPDA,
+            seasonData: currentSeasonDataPDA,
+            userReputation: user3ReputationPDA,
+            admin: admin.publicKey,
+            user: user3.publicKey,
+          })
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Should have failed while the season is active");
+      } catch (error) {
+        expect(error.message).to.include("SeasonStillActive");
+      }
+    });
+
+    it("Should freeze the standings when the season ends", async () => {
+      const before = await program.account.seasonData.fetch(currentSeasonDataPDA);
+
+      await program.methods
+        .endCurrentSeason(2)
+        .accounts({
+          config: configPDA,
+          seasonData: currentSeasonDataPDA,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      const after = await program.account.seasonData.fetch(currentSeasonDataPDA);
+      expect(after.isActive).to.be.false;
+      expect(after.leaderboard.map(entry => entry.user.toString())).to.deep.equal(
+        before.leaderboard.map(entry => entry.user.toString())
+      );
+    });
+
+    it("Should keep each user's final season rank when resetting seasonal data", async () => {
+      const seasonData = await program.account.seasonData.fetch(currentSeasonDataPDA);
+      const user3Entry = seasonData.leaderboard.find(entry => entry.user.equals(user3.publicKey));
+
+      await program.methods
+        .bulkResetSeasonalData(2, [user3.publicKey], 0)
+        .accounts({
+          config: configPDA,
+          seasonData: currentSeasonDataPDA,
+          admin: admin.publicKey,
+          batchJob: null,
+        })
+        .remainingAccounts([{ pubkey: user3ReputationPDA, isWritable: true, isSigner: false }])
+        .signers([admin])
+        .rpc();
+
+      const user3Rep = await program.account.userReputation.fetch(user3ReputationPDA);
+      expect(user3Rep.bestSeasonRank).to.equal(user3Entry.rank);
+      expect(user3Rep.lastClosedSeason).to.equal(2);
+      user3Rep.seasonalPoints.forEach(points => expect(points.toNumber()).to.equal(0));
+    });
+
+    it("Should close each user's season only once", async () => {
+      try {
+        await program.methods
+          .resetSeasonalPoints(2)
+          .accounts({
+            config: configPDA,
+            seasonData: currentSeasonDataPDA,
+            userReputation: user3ReputationPDA,
+            admin: admin.publicKey,
+            user: user3.publicKey,
+          })
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Should have failed because season 2 was already closed");
+      } catch (error) {
+        expect(error.message).to.include("SeasonAlreadyClosed");
+      }
+    });
+  });
+
+  describe("Reputation Migration", () => {
+    const migrationId = 1;
+    const migrationPDA = PublicKey.findProgramAddressSync(
+      [Buffer.from("reputation_migration"), new Uint8Array([1, 0, 0, 0])],
+      program.programId
+    )[0];
+    const legacyUser = Keypair.generate();
+
+    const sha256 = (...parts: Buffer[]) => createHash("sha256").update(Buffer.concat(parts)).digest();
+
+    // Borsh layout of BulkReputationUpdate, prefixed with the leaf domain byte
+    const leafHash = (record) => {
+      const data = Buffer.alloc(32 + 8 * 4 + 4 + 1);
+      record.user.toBuffer().copy(data, 0);
+      record.categoryPoints.forEach((points, i) => points.toArrayLike(Buffer, "le", 8).copy(data, 32 + 8 * i));
+      data.writeUInt32LE(record.achievements, 64);
+      data.writeUInt8(record.roleLevel, 68);
+      return sha256(Buffer.from([0]), data);
+    };
+    const nodeHash = (a: Buffer, b: Buffer) =>
+      Buffer.compare(a, b) <= 0 ? sha256(Buffer.from([1]), a, b) : sha256(Buffer.from([1]), b, a);
+
+    const records = [
+      {
+        user: legacyUser.publicKey,
+        categoryPoints: [new anchor.BN(1200), new anchor.BN(300), new anchor.BN(0), new anchor.BN(0)],
+        achievements: 0,
+        roleLevel: 2,
+      },
+      {
+        user: user3.publicKey,
+        categoryPoints: [new anchor.BN(50), new anchor.BN(50), new anchor.BN(50), new anchor.BN(50)],
+        achievements: 1,
+        roleLevel: 1,
+      },
+    ];
+    const leaves = records.map(leafHash);
+    const root = nodeHash(leaves[0], leaves[1]);
+
+    const claimAccounts = (user: PublicKey, claimer: PublicKey = admin.publicKey) => ({
+      config: configPDA,
+      migration: migrationPDA,
+      importClaim: PublicKey.findProgramAddressSync(
+        [Buffer.from("import_claim"), migrationPDA.toBuffer(), user.toBuffer()],
+        program.programId
+      )[0],
+      userReputation: PublicKey.findProgramAddressSync(
+        [Buffer.from("user_reputation"), user.toBuffer()],
+        program.programId
+      )[0],
+      leaderboards: leaderboardAccounts,
+      claimer,
+      systemProgram: SystemProgram.programId,
+    });
+
+    it("Should let the admin commit a migration root", async () => {
+      await program.methods
+        .importReputationData(migrationId, Array.from(root), records.length)
+        .accounts({
+          config: configPDA,
+          migration: migrationPDA,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const migration = await program.account.reputationMigration.fetch(migrationPDA);
+      expect(Buffer.from(migration.merkleRoot).equals(root)).to.be.true;
+      expect(migration.recordCount).to.equal(2);
+      expect(migration.claimedCount).to.equal(0);
+    });
+
+    it("Should create a new user's account from a claimed record", async () => {
+      const configBefore = await program.account.reputationConfig.fetch(configPDA);
+
+      await program.methods
+        .claimImportedReputation(migrationId, records[0], [Array.from(leaves[1])])
+        .accounts(claimAccounts(legacyUser.publicKey))
+        .signers([admin])
+        .rpc();
+
+      const legacyRep = await program.account.userReputation.fetch(
+        claimAccounts(legacyUser.publicKey).userReputation
+      );
+      expect(legacyRep.user.toString()).to.equal(legacyUser.publicKey.toString());
+      expect(legacyRep.categoryPoints[0].toNumber()).to.equal(1200);
+      expect(legacyRep.roleLevel).to.equal(2);
+
+      const configAfter = await program.account.reputationConfig.fetch(configPDA);
+      expect(configAfter.totalUsers.toNumber()).to.equal(configBefore.totalUsers.toNumber() + 1);
+    });
+
+    it("Should reject a record that is not under the root", async () => {
+      const forged = { ...records[1], roleLevel: 5 };
+      try {
+        await program.methods
+          .claimImportedReputation(migrationId, forged, [Array.from(leaves[0])])
+          .accounts(claimAccounts(user3.publicKey))
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Should have failed due to invalid proof");
+      } catch (error) {
+        expect(error.message).to.include("InvalidMerkleProof");
+      }
+    });
+
+    it("Should not let a third party overwrite an existing account", async () => {
+      try {
+        await program.methods
+          .claimImportedReputation(migrationId, records[1], [Array.from(leaves[0])])
+          .accounts(claimAccounts(user3.publicKey, user1.publicKey))
+          .signers([user1])
+          .rpc();
+
+        expect.fail("Should have failed because the claimer is neither the user nor the admin");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedImportClaim");
+      }
+    });
+
+    it("Should overwrite an existing account only once", async () => {
+      await program.methods
+        .claimImportedReputation(migrationId, records[1], [Array.from(leaves[0])])
+        .accounts(claimAccounts(user3.publicKey))
+        .signers([admin])
+        .rpc();
+
+      const user3Rep = await program.account.userReputation.fetch(user3ReputationPDA);
+      user3Rep.categoryPoints.forEach(points => expect(points.toNumber()).to.equal(50));
+      // Award metadata follows the imported achievements
+      expect(user3Rep.achievementAwardedAt[0].toNumber()).to.be.greaterThan(0);
+      user3Rep.achievementAwardedAt.slice(1).forEach(awardedAt => expect(awardedAt.toNumber()).to.equal(0));
+      user3Rep.achievementSeasons.slice(1).forEach(season => expect(season).to.equal(0));
+
+      try {
+        await program.methods
+          .claimImportedReputation(migrationId, records[1], [Array.from(leaves[0])])
+          .accounts(claimAccounts(user3.publicKey))
+          .signers([admin])
+          .rpc();
+
+        expect.fail("Should have failed because the record was already claimed");
+      } catch (error) {
+        expect(error.message).to.include("already in use");
+      }
+
+      const migration = await program.account.reputationMigration.fetch(migrationPDA);
+      expect(migration.claimedCount).to.equal(2);
+    });
+  });
+});